@@ -1,23 +1,57 @@
 use proc_macro::TokenStream;
-use proc_macro2::{Literal, Span, TokenStream as TokenStream2, TokenTree};
-use quote::{quote, ToTokens, TokenStreamExt};
+use proc_macro2::{Group, Literal, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use syn::spanned::Spanned;
 use syn::*;
 
-#[proc_macro_derive(Finite)]
+/// Derives `Finite` for a struct or enum whose fields all implement `Finite`. Values are ordered
+/// by variant, and then lexicographically by field, consistent with the derived `Ord`.
+///
+/// The implementation can be configured using `#[finite(...)]` attributes, which are listed in
+/// the documentation of the `Finite` trait.
+#[proc_macro_derive(Finite, attributes(finite))]
 pub fn derive_finite(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let options = match Options::parse(&input.attrs) {
+        Ok(options) => options,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
     let name = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-    let (count, index_of, nth) = match input.data {
+
+    // Require all type parameters to be `Finite`
+    let mut generics = input.generics.clone();
+    for param in input.generics.type_params() {
+        let param = &param.ident;
+        let where_clause = generics.make_where_clause();
+        where_clause
+            .predicates
+            .push(parse_quote! { #param: ::cantor::Finite });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Items within the body of a generic impl can't refer to its generic parameters, so
+    // intermediate constants must be defined as local variables instead
+    let local_consts = !input.generics.params.is_empty();
+    let mut assertions = Vec::new();
+    let mut const_fn_index_of_arms = Vec::new();
+    let mut const_fn_nth_arms = Vec::new();
+    let mut table_entries = Vec::new();
+    let (count, index_of, nth, nth_unchecked) = match input.data {
+        Data::Struct(_) if options.requires_fieldless() => {
+            let msg = "`table`, `const_fn` and `names` are only supported for enums";
+            return TokenStream::from(Error::new(Span::call_site(), msg).to_compile_error());
+        }
         Data::Struct(data) => match data.fields {
             Fields::Named(fields) => {
                 let mut field_tys = Vec::new();
+                let mut field_names = Vec::new();
                 let mut field_idents = Vec::new();
                 for field in fields.named {
-                    field_tys.push(field.ty.to_token_stream());
+                    field_tys.push(as_finite(&field));
+                    field_names.push(field.ident.to_token_stream().to_string());
                     field_idents.push(field.ident.to_token_stream());
                 }
-                let count = product_count(&field_tys);
+                let count = product_count(&name.to_string(), &field_tys, &field_names);
                 let index_of = product_index_of(&field_tys, &field_idents);
                 let nth = product_nth(
                     &field_tys,
@@ -33,23 +67,26 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
                     },
                     quote! {
                         if index < <Self as ::cantor::Finite>::COUNT {
-                            Some(#nth)
+                            Some(unsafe { <Self as ::cantor::Finite>::nth_unchecked(index) })
                         } else {
                             None
                         }
                     },
+                    Some(nth),
                 )
             }
             Fields::Unnamed(fields) => {
                 let mut field_tys = Vec::new();
+                let mut field_names = Vec::new();
                 let mut field_idents = Vec::new();
                 for field in fields.unnamed {
-                    field_tys.push(field.ty.to_token_stream());
+                    field_tys.push(as_finite(&field));
+                    field_names.push(field_idents.len().to_string());
                     let field_ident = format!("f{}", field_idents.len());
                     let field_ident = Ident::new(&field_ident, Span::call_site());
                     field_idents.push(field_ident.to_token_stream());
                 }
-                let count = product_count(&field_tys);
+                let count = product_count(&name.to_string(), &field_tys, &field_names);
                 let index_of = product_index_of(&field_tys, &field_idents);
                 let nth = product_nth(
                     &field_tys,
@@ -65,11 +102,12 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
                     },
                     quote! {
                         if index < <Self as ::cantor::Finite>::COUNT {
-                            Some(#nth)
+                            Some(unsafe { <Self as ::cantor::Finite>::nth_unchecked(index) })
                         } else {
                             None
                         }
                     },
+                    Some(nth),
                 )
             }
             Fields::Unit => (
@@ -82,32 +120,98 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
                         None
                     }
                 },
+                None,
             ),
         },
         Data::Enum(data) => {
             // Gather info from variants
             let mut count = SumExpr::new_zero();
+            let mut variant_counts = Vec::new();
             let mut const_count = SumExpr::new_zero();
             let mut consts = Vec::new();
             let mut index_of_arms = Vec::new();
             let mut nth_arms = Vec::new();
+            let mut starts = Vec::new();
+            let mut nth_builders = Vec::new();
+            let mut unit_run = Vec::new();
+            let mut is_fieldless = !data.variants.is_empty();
             for variant in data.variants {
-                // Consider the different types of variant definitions
+                let variant_options = match VariantOptions::parse(&variant.attrs) {
+                    Ok(variant_options) => variant_options,
+                    Err(err) => return TokenStream::from(err.to_compile_error()),
+                };
+                is_fieldless &= !variant_options.skip
+                    && matches!(variant.fields, Fields::Unit)
+                    && variant.discriminant.is_none();
+
+                // Skipped variants aren't enumerated
                 let variant_name = variant.ident;
+                if variant_options.skip {
+                    let msg = format!("`{}::{}` is skipped by `Finite`", name, variant_name);
+                    index_of_arms.push(quote! {
+                        Self::#variant_name { .. } => panic!(#msg)
+                    });
+                    const_fn_index_of_arms.push(quote! {
+                        Self::#variant_name { .. } => panic!(#msg)
+                    });
+                    continue;
+                }
+
+                // Verify the pinned index of the variant, if any
+                if let Some(index) = &variant_options.index {
+                    let expected = match index.base10_parse::<i64>() {
+                        Ok(expected) => expected,
+                        Err(err) => return TokenStream::from(err.to_compile_error()),
+                    };
+                    if count.non_lit.is_empty() {
+                        if count.lit != expected {
+                            let msg = format!(
+                                "`{}` starts at index {}, not {}",
+                                variant_name, count.lit, expected
+                            );
+                            let err = Error::new_spanned(index, msg);
+                            return TokenStream::from(err.to_compile_error());
+                        }
+                    } else if local_consts {
+                        let msg = "the index of this variant depends on generic parameters";
+                        let err = Error::new_spanned(index, msg);
+                        return TokenStream::from(err.to_compile_error());
+                    } else {
+                        let msg =
+                            format!("`{}` does not start at index {}", variant_name, expected);
+                        assertions.push(quote! {
+                            const _: () = assert!(#count == #index, #msg);
+                        });
+                    }
+                }
+
+                // Consider the different types of variant definitions
+                if options.requires_fieldless() && !matches!(variant.fields, Fields::Unit) {
+                    let msg = "`table`, `const_fn` and `names` require all enumerated variants to \
+                        be fieldless";
+                    let err = Error::new_spanned(variant.fields, msg);
+                    return TokenStream::from(err.to_compile_error());
+                }
+                if !matches!(variant.fields, Fields::Unit) {
+                    nth_arms.extend(unit_run_nth_arm(&mut unit_run, local_consts));
+                }
                 let start_index = const_count.get_simple(&mut consts);
                 const_count.set_zero();
                 const_count.add(start_index.clone().into());
+                starts.push(start_index.clone());
                 match variant.fields {
                     Fields::Named(fields) => {
                         let mut field_tys = Vec::new();
+                        let mut field_names = Vec::new();
                         let mut field_idents = Vec::new();
                         for field in fields.named {
-                            field_tys.push(field.ty.to_token_stream());
+                            field_tys.push(as_finite(&field));
+                            field_names.push(field.ident.to_token_stream().to_string());
                             field_idents.push(field.ident.to_token_stream());
                         }
                         let index_of_arm = product_index_of(&field_tys, &field_idents);
                         index_of_arms.push(quote! {
-                            Self::#variant_name { #(#field_idents),* } => #count + #index_of_arm
+                            Self::#variant_name { #(#field_idents),* } => #start_index + #index_of_arm
                         });
                         let nth_arm = product_nth(
                             &field_tys,
@@ -115,30 +219,41 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
                             &field_idents,
                             quote! { Self::#variant_name { #(#field_idents),* } },
                         );
-                        let variant_count = product_count(&field_tys);
+                        nth_builders.push(product_nth(
+                            &field_tys,
+                            quote! { __index },
+                            &field_idents,
+                            quote! { Self::#variant_name { #(#field_idents),* } },
+                        ));
+                        let variant_count = product_count(
+                            &format!("{}::{}", name, variant_name),
+                            &field_tys,
+                            &field_names,
+                        );
                         count.add(variant_count.clone());
+                        variant_counts.push((variant_name.to_string(), variant_count.clone()));
                         const_count.add(variant_count);
-                        const_count.add(NumTerm::Literal(-1));
                         let end_index = const_count.get_simple(&mut consts);
                         const_count.set_zero();
                         const_count.add(end_index.clone().into());
-                        const_count.add(NumTerm::Literal(1));
                         nth_arms.push(quote! {
-                            #start_index..=#end_index => Some(#nth_arm)
+                            _ if index < #end_index => Some(#nth_arm)
                         });
                     }
                     Fields::Unnamed(fields) => {
                         let mut field_tys = Vec::new();
+                        let mut field_names = Vec::new();
                         let mut field_idents = Vec::new();
                         for field in fields.unnamed {
-                            field_tys.push(field.ty.to_token_stream());
+                            field_tys.push(as_finite(&field));
+                            field_names.push(field_idents.len().to_string());
                             let field_ident = format!("f{}", field_idents.len());
                             let field_ident = Ident::new(&field_ident, Span::call_site());
                             field_idents.push(field_ident.to_token_stream());
                         }
                         let index_of_arm = product_index_of(&field_tys, &field_idents);
                         index_of_arms.push(quote! {
-                            Self::#variant_name(#(#field_idents),*) => #count + #index_of_arm
+                            Self::#variant_name(#(#field_idents),*) => #start_index + #index_of_arm
                         });
                         let nth_arm = product_nth(
                             &field_tys,
@@ -146,51 +261,129 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
                             &field_idents,
                             quote! { Self::#variant_name(#(#field_idents),*) },
                         );
-                        let variant_count = product_count(&field_tys);
+                        nth_builders.push(product_nth(
+                            &field_tys,
+                            quote! { __index },
+                            &field_idents,
+                            quote! { Self::#variant_name(#(#field_idents),*) },
+                        ));
+                        let variant_count = product_count(
+                            &format!("{}::{}", name, variant_name),
+                            &field_tys,
+                            &field_names,
+                        );
                         count.add(variant_count.clone());
+                        variant_counts.push((variant_name.to_string(), variant_count.clone()));
                         const_count.add(variant_count);
-                        const_count.add(NumTerm::Literal(-1));
                         let end_index = const_count.get_simple(&mut consts);
                         const_count.set_zero();
                         const_count.add(end_index.clone().into());
-                        const_count.add(NumTerm::Literal(1));
                         nth_arms.push(quote! {
-                            #start_index..=#end_index => Some(#nth_arm)
+                            _ if index < #end_index => Some(#nth_arm)
                         });
                     }
                     Fields::Unit => {
                         index_of_arms.push(quote! {
                             Self::#variant_name => #start_index
                         });
-                        nth_arms.push(quote! {
-                            #start_index => Some(Self::#variant_name)
-                        });
+                        unit_run.push((start_index, variant_name.clone()));
+                        nth_builders.push(quote! { Self::#variant_name });
+                        let index = Literal::usize_unsuffixed(table_entries.len());
+                        const_fn_index_of_arms.push(quote! { Self::#variant_name => #index });
+                        const_fn_nth_arms.push(quote! { #index => Some(Self::#variant_name) });
+                        table_entries.push(variant_name.clone());
                         count.add(NumTerm::Literal(1));
+                        variant_counts.push((variant_name.to_string(), NumTerm::Literal(1)));
                         const_count.add(NumTerm::Literal(1));
                     }
                 };
             }
+            nth_arms.extend(unit_run_nth_arm(&mut unit_run, local_consts));
             nth_arms.push(quote! { _ => None });
-            (
-                quote! { #count },
+            let consts = define_consts(&consts, local_consts);
+            let index_of = if is_fieldless {
+                // Variant discriminants coincide with indices
+                quote! { value as usize }
+            } else {
                 quote! {
-                    #(#consts)*
+                    #consts
                     match value {
                         #(#index_of_arms,)*
                     }
-                },
+                }
+            };
+            // Fieldless enums with a primitive representation are typically used as opcodes or
+            // similar, where a table lookup is preferable to the default match
+            let use_table = options.table
+                || (is_fieldless && !options.binary_search && has_primitive_repr(&input.attrs));
+            let mut nth_unchecked = None;
+            let nth = if use_table {
+                // Look up the value in a table of all values
+                let num_values = table_entries.len();
+                let table = define_consts(
+                    &[(
+                        Ident::new("TABLE", Span::call_site()),
+                        quote! { [#name #ty_generics; #num_values] },
+                        quote! { [#(#name::#table_entries),*] },
+                    )],
+                    local_consts,
+                );
+                nth_unchecked = Some(quote! {
+                    #table
+                    TABLE.get_unchecked(index).clone()
+                });
+                quote! {
+                    #table
+                    TABLE.get(index).cloned()
+                }
+            } else if options.binary_search {
+                // Find the variant using a binary search over a table of variant offsets
+                let num_variants = starts.len();
+                let variant_indices = 0..num_variants;
+                let offsets = define_consts(
+                    &[(
+                        Ident::new("OFFSETS", Span::call_site()),
+                        quote! { [usize; #num_variants] },
+                        quote! { [#(#starts),*] },
+                    )],
+                    local_consts,
+                );
                 quote! {
-                    #(#consts)*
+                    #consts
+                    #offsets
+                    if index >= <Self as ::cantor::Finite>::COUNT {
+                        return None;
+                    }
+                    let __variant = OFFSETS.partition_point(|offset| *offset <= index) - 1;
+                    let __index = index - OFFSETS[__variant];
+                    match __variant {
+                        #(#variant_indices => Some(#nth_builders),)*
+                        _ => unreachable!(),
+                    }
+                }
+            } else {
+                quote! {
+                    #consts
                     match index {
                         #(#nth_arms,)*
                     }
-                },
-            )
+                }
+            };
+            let count = sum_count(&name.to_string(), &variant_counts);
+            (count, index_of, nth, nth_unchecked)
         }
         Data::Union(_) => todo!(),
     };
 
-    // Build implementation
+    // Build implementation, only overriding `nth_unchecked` where it can skip the bounds check
+    let nth_unchecked = nth_unchecked.map(|nth_unchecked| {
+        quote! {
+            #[inline]
+            unsafe fn nth_unchecked(index: usize) -> Self {
+                #nth_unchecked
+            }
+        }
+    });
     let mut res = quote! {
         #[automatically_derived]
         unsafe impl #impl_generics ::cantor::Finite for #name #ty_generics #where_clause {
@@ -203,20 +396,324 @@ pub fn derive_finite(input: TokenStream) -> TokenStream {
             fn nth(index: usize) -> Option<Self> {
                 #nth
             }
+
+            #nth_unchecked
         }
     };
 
-    // If this is a concrete type (no generic parameters), also implement helper traits.
-    if input.generics.type_params().next().is_none() {
+    res.extend(assertions);
+
+    // Implement conversions to and from `usize`, if requested
+    if options.convert {
         res.extend(quote! {
-            ::cantor::impl_concrete_finite!(#name);
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<usize> for #name #ty_generics #where_clause {
+                type Error = ::cantor::TryFromIndexError;
+                fn try_from(index: usize) -> Result<Self, Self::Error> {
+                    <Self as ::cantor::Finite>::try_nth(index)
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for usize #where_clause {
+                fn from(value: #name #ty_generics) -> usize {
+                    <#name #ty_generics as ::cantor::Finite>::index_of(value)
+                }
+            }
         });
     }
 
+    // Implement const versions of `index_of` and `nth`, if requested
+    if options.const_fn {
+        let vis = &input.vis;
+        res.extend(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// A `const` version of [`::cantor::Finite::index_of`].
+                #vis const fn index_of_const(self) -> usize {
+                    match self {
+                        #(#const_fn_index_of_arms,)*
+                    }
+                }
+
+                /// A `const` version of [`::cantor::Finite::nth`].
+                #vis const fn nth_const(index: usize) -> Option<Self> {
+                    match index {
+                        #(#const_fn_nth_arms,)*
+                        _ => None,
+                    }
+                }
+            }
+        });
+    }
+
+    // Implement variant names, if requested
+    if options.names {
+        let vis = &input.vis;
+        let num_names = table_entries.len();
+        let names = table_entries.iter().map(|variant| variant.to_string());
+        let names_again = names.clone();
+        res.extend(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// The names of the variants of this type, in order of their index.
+                #vis const NAMES: [&'static str; #num_names] = [#(#names),*];
+
+                /// Gets the name of the variant for this value.
+                #vis fn name(self) -> &'static str {
+                    Self::NAMES[<Self as ::cantor::Finite>::index_of(self)]
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                type Err = ::cantor::ParseNameError;
+                fn from_str(name: &str) -> Result<Self, Self::Err> {
+                    match name {
+                        #(#names_again => Ok(#name::#table_entries),)*
+                        _ => Err(::cantor::ParseNameError),
+                    }
+                }
+            }
+        });
+    }
+
+    // Generate a test for the implementation, if requested
+    if options.self_test {
+        let tys = if input.generics.type_params().next().is_none() {
+            vec![quote! { #name }]
+        } else if options.concrete.is_empty() {
+            let msg = "`self_test` on a generic type requires `concrete` instantiations";
+            return TokenStream::from(Error::new(Span::call_site(), msg).to_compile_error());
+        } else {
+            options.concrete.iter().map(|ty| quote! { #ty }).collect()
+        };
+        let test_name = Ident::new(&format!("finite_self_test_{}", name), Span::call_site());
+        res.extend(quote! {
+            #[cfg(test)]
+            #[test]
+            #[allow(non_snake_case)]
+            fn #test_name() {
+                #(::cantor::validate::<#tys>().unwrap();)*
+            }
+        });
+    }
+
+    // If this is a concrete type (no generic parameters), also implement helper traits. Otherwise,
+    // implement them for the requested concrete instantiations.
+    if input.generics.type_params().next().is_none() {
+        if let Some(ty) = options.concrete.first() {
+            let msg = "`concrete` instantiations are only needed for generic types";
+            return TokenStream::from(Error::new_spanned(ty, msg).to_compile_error());
+        }
+        let concrete = quote! { #name };
+        res.extend(impl_concrete_finite(&concrete, &options));
+    } else {
+        for concrete in options.concrete.iter() {
+            res.extend(impl_concrete_finite(concrete, &options));
+        }
+    }
+
     // Return final result
     TokenStream::from(res)
 }
 
+/// Invokes `impl_concrete_finite` for the given concrete type.
+fn impl_concrete_finite(ty: &impl ToTokens, options: &Options) -> TokenStream2 {
+    match &options.index_type {
+        Some(index_type) => {
+            quote! { ::cantor::impl_concrete_finite!(#ty, index_type = #index_type); }
+        }
+        None => quote! { ::cantor::impl_concrete_finite!(#ty); },
+    }
+}
+
+/// Gets the `nth` match arm for a run of consecutive unit variants, given the start index and name
+/// of each variant in the run. This clears the run. If `local_consts` is set, the start indices may
+/// not be used as patterns.
+fn unit_run_nth_arm(
+    run: &mut Vec<(SimpleNumTerm, Ident)>,
+    local_consts: bool,
+) -> Option<TokenStream2> {
+    let res = match run.as_slice() {
+        [] => None,
+        [(start_index, _), ..] if local_consts => {
+            let (end_index, _) = run.last().unwrap();
+            let variant_names = run.iter().map(|(_, variant_name)| variant_name);
+            Some(quote! {
+                _ if index <= #end_index => Some(
+                    [#(Self::#variant_names),*][index - #start_index].clone()
+                )
+            })
+        }
+        [(start_index, variant_name)] => Some(quote! {
+            #start_index => Some(Self::#variant_name)
+        }),
+        [(start_index, _), .., (end_index, _)] => {
+            let variant_names = run.iter().map(|(_, variant_name)| variant_name);
+            Some(quote! {
+                #start_index..=#end_index => Some(
+                    [#(Self::#variant_names),*][index - #start_index].clone()
+                )
+            })
+        }
+    };
+    run.clear();
+    res
+}
+
+/// Gets the definitions for the given intermediate constants, each given by a name, type and value.
+/// If `local_consts` is set, these are defined as local variables rather than constant items.
+fn define_consts(
+    consts: &[(Ident, TokenStream2, TokenStream2)],
+    local_consts: bool,
+) -> TokenStream2 {
+    let names = consts.iter().map(|(name, _, _)| name);
+    let tys = consts.iter().map(|(_, ty, _)| ty);
+    let values = consts.iter().map(|(_, _, value)| value);
+    if local_consts {
+        quote! {
+            #(#[allow(non_snake_case, unused_variables)] let #names: #tys = #values;)*
+        }
+    } else {
+        quote! {
+            #(const #names: #tys = #values;)*
+        }
+    }
+}
+
+/// Options for the derived implementation, specified using `#[finite(...)]` attributes on the
+/// type.
+#[derive(Default)]
+struct Options {
+    /// For enums, implement `nth` using a binary search over a table of variant offsets rather
+    /// than a chain of range comparisons.
+    binary_search: bool,
+
+    /// For fieldless enums, implement `nth` by indexing into a table of all values.
+    table: bool,
+
+    /// For fieldless enums, also implement inherent `index_of_const` and `nth_const` methods which
+    /// can be used in `const` contexts.
+    const_fn: bool,
+
+    /// Also implement `TryFrom<usize>` for the type and `From<T>` for `usize`, using `nth` and
+    /// `index_of` respectively.
+    convert: bool,
+
+    /// For generic types, the concrete instantiations that helper traits should be implemented for.
+    concrete: Vec<Type>,
+
+    /// The index type to use for `Compress`, overriding the smallest type that fits.
+    index_type: Option<Type>,
+
+    /// Generate a unit test that checks the implementation.
+    self_test: bool,
+
+    /// For fieldless enums, also implement a table of variant names and `FromStr`.
+    names: bool,
+}
+
+impl Options {
+    /// Parses the options from the attributes of a type.
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Options::default();
+        for item in finite_attr_items(attrs)? {
+            match &item {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("binary_search") => {
+                    options.binary_search = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("table") => {
+                    options.table = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("const_fn") => {
+                    options.const_fn = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("convert") => {
+                    options.convert = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("self_test") => {
+                    options.self_test = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("names") => {
+                    options.names = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("concrete") => {
+                    options.concrete.push(lit.parse()?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("index_type") => {
+                    options.index_type = Some(lit.parse()?);
+                }
+                _ => return Err(Error::new_spanned(item, "unrecognized `finite` option")),
+            }
+        }
+        if options.binary_search && options.table {
+            let msg = "`binary_search` and `table` can't be used together";
+            return Err(Error::new(Span::call_site(), msg));
+        }
+        Ok(options)
+    }
+
+    /// Determines whether these options require all enumerated variants to be fieldless.
+    fn requires_fieldless(&self) -> bool {
+        self.table || self.const_fn || self.names
+    }
+}
+
+/// Options for a particular enum variant, specified using `#[finite(...)]` attributes.
+#[derive(Default)]
+struct VariantOptions {
+    /// Exclude this variant from the enumeration. `index_of` will panic if given this variant.
+    skip: bool,
+
+    /// The index this variant is expected to start at. It is an error for the variant to start at
+    /// any other index, so that reordering or changing variants can't silently change indices.
+    index: Option<LitInt>,
+}
+
+impl VariantOptions {
+    /// Parses the options from the attributes of a variant.
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = VariantOptions::default();
+        for item in finite_attr_items(attrs)? {
+            match &item {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                    options.skip = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(lit),
+                    ..
+                })) if path.is_ident("index") => {
+                    options.index = Some(lit.clone());
+                }
+                _ => return Err(Error::new_spanned(item, "unrecognized `finite` option")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Gets the items in all `#[finite(...)]` attributes in the given list.
+fn finite_attr_items(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut items = Vec::new();
+    for attr in attrs {
+        if attr.path.is_ident("finite") {
+            match attr.parse_meta()? {
+                Meta::List(list) => items.extend(list.nested),
+                meta => return Err(Error::new_spanned(meta, "expected `#[finite(...)]`")),
+            }
+        }
+    }
+    Ok(items)
+}
+
 /// A [`NumTerm`] that can be used as a range bound.
 #[derive(Clone)]
 enum SimpleNumTerm {
@@ -311,7 +808,10 @@ impl SumExpr {
 
     /// Gets a [`SimpleNumTerm`] representation of this expression, assuming its possible to define
     /// an arbitrary constant ahead of time.
-    pub fn get_simple(&mut self, consts: &mut Vec<TokenStream2>) -> SimpleNumTerm {
+    pub fn get_simple(
+        &mut self,
+        consts: &mut Vec<(Ident, TokenStream2, TokenStream2)>,
+    ) -> SimpleNumTerm {
         if self.non_lit.is_empty() {
             return SimpleNumTerm::Literal(self.lit);
         } else if self.lit == 0 && self.non_lit.len() == 1 {
@@ -321,7 +821,7 @@ impl SumExpr {
         }
         let ident = format!("C_{}", consts.len());
         let ident = Ident::new(&ident, Span::call_site());
-        consts.push(quote! { const #ident: usize = #self; });
+        consts.push((ident.clone(), quote! { usize }, quote! { #self }));
         SimpleNumTerm::Constant(ident)
     }
 }
@@ -344,34 +844,84 @@ impl ToTokens for SumExpr {
     }
 }
 
-/// Gets an expression for the number of values for a product of the given types.
-fn product_count(field_tys: &[TokenStream2]) -> NumTerm {
+/// Gets an expression for the number of values for a product of the given types, each given as
+/// a path to its [`Finite`] implementation (see [`as_finite`]). `path` and `fields` are used to
+/// report overflows.
+fn product_count(path: &str, field_tys: &[TokenStream2], fields: &[String]) -> NumTerm {
     if let Some((head_field_ty, tail_field_tys)) = field_tys.split_first() {
+        if tail_field_tys.is_empty() {
+            return NumTerm::Complex(quote! { #head_field_ty::COUNT });
+        }
+        let msgs = fields[1..].iter().map(|field| {
+            format!(
+                "`COUNT` overflows `usize` for `{}` at field `{}`",
+                path, field
+            )
+        });
         NumTerm::Complex(quote! {
-            <#head_field_ty as ::cantor::Finite>::COUNT
-            #(* <#tail_field_tys as ::cantor::Finite>::COUNT)*
+            {
+                let __count = #head_field_ty::COUNT;
+                #(let __count = match __count.checked_mul(#tail_field_tys::COUNT) {
+                    Some(count) => count,
+                    None => panic!(#msgs),
+                };)*
+                __count
+            }
         })
     } else {
         NumTerm::Literal(1)
     }
 }
 
+/// Gets an expression for the number of values for a sum of the given variants, given the name and
+/// number of values of each variant. `path` is used to report overflows.
+fn sum_count(path: &str, variant_counts: &[(String, NumTerm)]) -> TokenStream2 {
+    let mut lit = 0;
+    let mut non_lits = Vec::new();
+    let mut msgs = Vec::new();
+    for (variant, variant_count) in variant_counts {
+        match variant_count {
+            NumTerm::Literal(value) => lit += value,
+            _ => {
+                non_lits.push(variant_count);
+                msgs.push(format!(
+                    "`COUNT` overflows `usize` for `{}` at variant `{}`",
+                    path, variant
+                ));
+            }
+        }
+    }
+    let lit = Literal::i64_unsuffixed(lit);
+    if non_lits.is_empty() {
+        return quote! { #lit };
+    }
+    quote! {
+        {
+            let __count: usize = #lit;
+            #(let __count = match __count.checked_add(#non_lits) {
+                Some(count) => count,
+                None => panic!(#msgs),
+            };)*
+            __count
+        }
+    }
+}
+
 /// Gets an expression which produces the index of a value of the product type, given the values
 /// of its fields.
 fn product_index_of(field_tys: &[TokenStream2], fields: &[TokenStream2]) -> TokenStream2 {
     quote! {
         {
             let __index = 0;
-            #(let __index = __index *
-                <#field_tys as ::cantor::Finite>::COUNT +
-                <#field_tys as ::cantor::Finite>::index_of(#fields);)*
+            #(let __index = __index * #field_tys::COUNT + #field_tys::index_of(#fields);)*
             __index
         }
     }
 }
 
 /// Gets an expression which produces a value of the product, given an expression for a
-/// valid index and a constructor for values of the product.
+/// valid index and a constructor for values of the product. Since the index of each field is
+/// reduced modulo the field's `COUNT`, the field lookups can never fail and are done unchecked.
 fn product_nth(
     field_tys: &[TokenStream2],
     index: TokenStream2,
@@ -384,11 +934,67 @@ fn product_nth(
         {
             let __index = #index;
             #(
-                let #fields_rev = <#field_tys_rev as ::cantor::Finite>::nth(__index %
-                    <#field_tys_rev as ::cantor::Finite>::COUNT).unwrap();
-                let __index = __index / <#field_tys_rev as ::cantor::Finite>::COUNT;
+                let #fields_rev = unsafe {
+                    #field_tys_rev::nth_unchecked(__index % #field_tys_rev::COUNT)
+                };
+                let __index = __index / #field_tys_rev::COUNT;
             )*
             #cons
         }
     }
 }
+
+/// Gets a qualified path to the [`Finite`] implementation for the type of the given field. This
+/// is spanned to the field's name (or its type, for unnamed fields) so that errors for
+/// non-`Finite` fields point to the field.
+fn as_finite(field: &Field) -> TokenStream2 {
+    match &field.ident {
+        Some(ident) => {
+            let field_ty = respan(field.ty.to_token_stream(), ident.span());
+            quote_spanned! { ident.span() => <#field_ty as ::cantor::Finite> }
+        }
+        None => {
+            let field_ty = &field.ty;
+            quote_spanned! { field_ty.span() => <#field_ty as ::cantor::Finite> }
+        }
+    }
+}
+
+/// Sets the span of every token in the given stream.
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let stream = respan(group.stream(), span);
+                token = TokenTree::Group(Group::new(group.delimiter(), stream));
+            }
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+/// Determines whether a primitive integer representation is specified by a `#[repr(...)]`
+/// attribute.
+fn has_primitive_repr(attrs: &[Attribute]) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    for attr in attrs {
+        if attr.path.is_ident("repr") {
+            if let Ok(Meta::List(list)) = attr.parse_meta() {
+                for item in list.nested {
+                    if let NestedMeta::Meta(Meta::Path(path)) = item {
+                        if let Some(ident) = path.get_ident() {
+                            if PRIMITIVES.contains(&ident.to_string().as_str()) {
+                                return true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
@@ -1,16 +1,52 @@
 #![allow(missing_docs)]
 #![doc(hidden)]
+use core::convert::Infallible;
+use core::mem::MaybeUninit;
 
 /// Encapsulates the required operations for arrays required by this crate.
-pub trait Array<T> {
-    fn new(f: impl FnMut(usize) -> T) -> Self;
+pub trait Array<T>: Sized {
+    fn new(mut f: impl FnMut(usize) -> T) -> Self {
+        match Self::try_new(|i| Ok::<T, Infallible>(f(i))) {
+            Ok(array) => array,
+            Err(err) => match err {},
+        }
+    }
+    fn try_new<E>(f: impl FnMut(usize) -> Result<T, E>) -> Result<Self, E>;
     fn as_slice(&self) -> &[T];
     fn as_slice_mut(&mut self) -> &mut [T];
 }
 
 impl<T, const N: usize> Array<T> for [T; N] {
-    fn new(f: impl FnMut(usize) -> T) -> Self {
-        array_init::array_init(f)
+    fn try_new<E>(mut f: impl FnMut(usize) -> Result<T, E>) -> Result<Self, E> {
+        /// Drops the initialized prefix of a partially-initialized array if initialization is
+        /// aborted, either due to an error or a panic.
+        struct Guard<'a, T, const N: usize> {
+            array: &'a mut [MaybeUninit<T>; N],
+            len: usize,
+        }
+
+        impl<'a, T, const N: usize> Drop for Guard<'a, T, N> {
+            fn drop(&mut self) {
+                for item in &mut self.array[..self.len] {
+                    unsafe { item.assume_init_drop() };
+                }
+            }
+        }
+
+        // SAFETY: An array of `MaybeUninit` does not require initialization
+        let mut array: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut guard = Guard {
+            array: &mut array,
+            len: 0,
+        };
+        for i in 0..N {
+            guard.array[i].write(f(i)?);
+            guard.len += 1;
+        }
+        core::mem::forget(guard);
+
+        // SAFETY: All items have been initialized
+        Ok(unsafe { (&array as *const [MaybeUninit<T>; N] as *const [T; N]).read() })
     }
 
     fn as_slice(&self) -> &[T] {
@@ -20,4 +56,22 @@ impl<T, const N: usize> Array<T> for [T; N] {
     fn as_slice_mut(&mut self) -> &mut [T] {
         self
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_try_new_drop() {
+    use core::cell::Cell;
+    struct Counted<'a>(&'a Cell<usize>);
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+    let drops = Cell::new(0);
+    let res = <[Counted; 5]>::try_new(|i| if i < 3 { Ok(Counted(&drops)) } else { Err(i) });
+    assert!(matches!(res, Err(3)));
+    assert_eq!(drops.get(), 3);
+    let array = <[Counted; 5]>::new(|_| Counted(&drops));
+    drop(array);
+    assert_eq!(drops.get(), 8);
+}
@@ -0,0 +1,270 @@
+use crate::array::Array;
+use crate::*;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+/// A set of values of type `T`, implemented using an array of `bool`s indexed by
+/// [`Finite::index_of`] of the value.
+///
+/// This has the same interface as [`BitmapSet`], and can be used for types with too many values
+/// for a bitmap to be practical.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Pixel(u8, u8);
+///
+/// let mut set = ArraySet::none();
+/// set.include(Pixel(3, 4));
+/// set.include(Pixel(200, 1));
+/// assert_eq!(set.size(), 2);
+/// assert!(set.contains(Pixel(3, 4)));
+/// assert!(!set.contains(Pixel(4, 3)));
+/// ```
+pub struct ArraySet<T: ArrayFinite<bool>>(T::Array);
+
+impl<T: ArrayFinite<bool>> ArraySet<T> {
+    /// Constructs a new [`ArraySet`] with initial membership determined using the given function.
+    pub fn new(mut f: impl FnMut(T) -> bool) -> Self {
+        ArraySet(T::Array::new(|i| f(unsafe { T::nth_unchecked(i) })))
+    }
+
+    /// The set of all possible values of `T`.
+    pub fn all() -> Self {
+        ArraySet(T::Array::new(|_| true))
+    }
+
+    /// The empty set.
+    pub fn none() -> Self {
+        ArraySet(T::Array::new(|_| false))
+    }
+
+    /// The set consisting of only the given value.
+    pub fn only(value: T) -> Self {
+        let index = T::index_of(value);
+        ArraySet(T::Array::new(|i| i == index))
+    }
+
+    /// The number of values in this set.
+    pub fn size(&self) -> usize {
+        self.0.as_slice().iter().filter(|x| **x).count()
+    }
+
+    /// Determines whether this is the empty set.
+    pub fn is_none(&self) -> bool {
+        !self.0.as_slice().iter().any(|x| *x)
+    }
+
+    /// Determines whether this is the empty set. This is equivalent to [`ArraySet::is_none`].
+    pub fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    /// Determines whether this is the set of all possible values of `T`.
+    pub fn is_all(&self) -> bool {
+        self.0.as_slice().iter().all(|x| *x)
+    }
+
+    /// Determines whether every value in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let other = other.0.as_slice();
+        self.0.as_slice().iter().zip(other).all(|(a, b)| !a || *b)
+    }
+
+    /// Determines whether every value in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Determines whether this set has no values in common with `other`.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let other = other.0.as_slice();
+        !self.0.as_slice().iter().zip(other).any(|(a, b)| *a && *b)
+    }
+
+    /// Iterates over the values in this set, in increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = ArraySet::new(|x: u8| x > 250);
+    /// assert!(set.iter().eq(251..=255));
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        let slice = self.0.as_slice();
+        (0..slice.len())
+            .filter(move |i| slice[*i])
+            .map(|i| unsafe { T::nth_unchecked(i) })
+    }
+
+    /// Applies the given binary operation to corresponding entries of this set and `other`.
+    fn zip_with(&mut self, other: &Self, mut f: impl FnMut(bool, bool) -> bool) {
+        let other = other.0.as_slice();
+        for (a, b) in self.0.as_slice_mut().iter_mut().zip(other) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+impl<T: ArrayFinite<bool>> Default for ArraySet<T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: ArrayFinite<bool>> Set<T> for ArraySet<T> {
+    fn contains(&self, value: T) -> bool {
+        unsafe { *self.0.as_slice().get_unchecked(T::index_of(value)) }
+    }
+
+    fn include(&mut self, value: T) {
+        unsafe { *self.0.as_slice_mut().get_unchecked_mut(T::index_of(value)) = true }
+    }
+
+    fn exclude(&mut self, value: T) {
+        unsafe { *self.0.as_slice_mut().get_unchecked_mut(T::index_of(value)) = false }
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        let entry = unsafe { self.0.as_slice_mut().get_unchecked_mut(T::index_of(value)) };
+        !core::mem::replace(entry, true)
+    }
+
+    fn remove(&mut self, value: T) -> bool {
+        let entry = unsafe { self.0.as_slice_mut().get_unchecked_mut(T::index_of(value)) };
+        core::mem::replace(entry, false)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.size()
+    }
+
+    fn clear(&mut self) {
+        self.0.as_slice_mut().fill(false);
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & !b)
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitAnd<ArraySet<T>> for ArraySet<T> {
+    type Output = ArraySet<T>;
+    fn bitand(mut self, rhs: ArraySet<T>) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitOr<ArraySet<T>> for ArraySet<T> {
+    type Output = ArraySet<T>;
+    fn bitor(mut self, rhs: ArraySet<T>) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitXor<ArraySet<T>> for ArraySet<T> {
+    type Output = ArraySet<T>;
+    fn bitxor(mut self, rhs: ArraySet<T>) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<T: ArrayFinite<bool>> Sub<ArraySet<T>> for ArraySet<T> {
+    type Output = ArraySet<T>;
+    fn sub(mut self, rhs: ArraySet<T>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitOrAssign<ArraySet<T>> for ArraySet<T> {
+    fn bitor_assign(&mut self, rhs: ArraySet<T>) {
+        self.union_with(&rhs)
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitAndAssign<ArraySet<T>> for ArraySet<T> {
+    fn bitand_assign(&mut self, rhs: ArraySet<T>) {
+        self.intersect_with(&rhs)
+    }
+}
+
+impl<T: ArrayFinite<bool>> BitXorAssign<ArraySet<T>> for ArraySet<T> {
+    fn bitxor_assign(&mut self, rhs: ArraySet<T>) {
+        self.zip_with(&rhs, |a, b| a ^ b)
+    }
+}
+
+impl<T: ArrayFinite<bool>> SubAssign<ArraySet<T>> for ArraySet<T> {
+    fn sub_assign(&mut self, rhs: ArraySet<T>) {
+        self.difference_with(&rhs)
+    }
+}
+
+impl<T: ArrayFinite<bool>> Clone for ArraySet<T>
+where
+    T::Array: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ArrayFinite<bool>> Copy for ArraySet<T> where T::Array: Copy {}
+
+impl<T: ArrayFinite<bool>> PartialEq for ArraySet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T: ArrayFinite<bool>> Eq for ArraySet<T> {}
+
+impl<T: ArrayFinite<bool>> core::hash::Hash for ArraySet<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_slice().hash(state)
+    }
+}
+
+impl<T: core::fmt::Debug + ArrayFinite<bool>> core::fmt::Debug for ArraySet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[test]
+fn test_array_set() {
+    let a = ArraySet::new(|x: u8| x < 100);
+    let b = ArraySet::new(|x: u8| x >= 50);
+    assert_eq!((a & b).size(), 50);
+    assert_eq!((a | b), ArraySet::all());
+    assert_eq!((a ^ b).size(), 206);
+    assert!((a - b).iter().eq(0..50));
+    assert!((a - b).is_subset(&a) && a.is_superset(&(a - b)));
+    assert!((a - b).is_disjoint(&b));
+    assert!(ArraySet::<u8>::none().is_empty());
+    assert!(ArraySet::<u8>::all().is_all() && !a.is_all());
+    assert_eq!(ArraySet::only(7u8).iter().next_back(), Some(7));
+    let mut c = ArraySet::default();
+    assert!(c.insert(3u8));
+    assert!(!c.insert(3));
+    assert!(c.remove(3));
+    assert!(!c.remove(3));
+    assert!(c.is_none());
+}
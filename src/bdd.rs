@@ -0,0 +1,360 @@
+//! Reduced ordered binary decision diagrams (BDDs) whose variables are the values of a [`Finite`]
+//! type.
+//!
+//! A BDD is a compact representation of a boolean predicate over a set of boolean variables. Here,
+//! the variables are identified with the values of a type `V`, and are ordered according to
+//! [`Finite::index_of`]. An assignment of the variables can thus be thought of as a set of `V`
+//! values (the variables which are `true`).
+//!
+//! # Example
+//! ```
+//! use cantor::*;
+//! use cantor::bdd::Bdd;
+//!
+//! #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+//! enum Var {
+//!     A,
+//!     B,
+//!     C
+//! }
+//!
+//! let mut bdd = Bdd::new();
+//! let a = bdd.var(Var::A);
+//! let b = bdd.var(Var::B);
+//! let a_and_b = bdd.and(a, b);
+//! let f = bdd.not(a_and_b);
+//! assert_eq!(bdd.count_models(f), 6);
+//! assert!(bdd.eval(f, |v| v == Var::C));
+//! ```
+use crate::*;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// A reference to a node in a [`Bdd`], representing a boolean predicate. References are only
+/// meaningful with respect to the [`Bdd`] that created them.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct BddRef(usize);
+
+impl BddRef {
+    /// The predicate which is always `false`.
+    pub const FALSE: BddRef = BddRef(0);
+
+    /// The predicate which is always `true`.
+    pub const TRUE: BddRef = BddRef(1);
+
+    /// Determines whether this is one of the constant predicates, [`BddRef::FALSE`] or
+    /// [`BddRef::TRUE`].
+    pub fn is_const(&self) -> bool {
+        self.0 < 2
+    }
+}
+
+/// A decision node in a [`Bdd`].
+#[derive(Clone, Copy)]
+struct Node {
+    var: usize,
+    low: BddRef,
+    high: BddRef,
+}
+
+/// A binary operation which can be applied to a pair of predicates.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+/// A manager for reduced ordered binary decision diagrams over variables of type `V`. All
+/// predicates created by a manager share structure, so equivalent predicates are always
+/// represented by the same [`BddRef`].
+pub struct Bdd<V: Finite> {
+    nodes: Vec<Node>,
+    unique: BTreeMap<(usize, BddRef, BddRef), BddRef>,
+    cache: BTreeMap<(Op, BddRef, BddRef), BddRef>,
+    marker: PhantomData<fn(V)>,
+}
+
+impl<V: Finite> Bdd<V> {
+    /// Constructs a new [`Bdd`] manager which initially contains only the constant predicates.
+    pub fn new() -> Self {
+        let terminal = Node {
+            var: V::COUNT,
+            low: BddRef::FALSE,
+            high: BddRef::FALSE,
+        };
+        Self {
+            nodes: alloc::vec![terminal, terminal],
+            unique: BTreeMap::new(),
+            cache: BTreeMap::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// The number of decision nodes currently stored in this manager.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// Gets the constant predicate with the given value.
+    pub fn constant(&self, value: bool) -> BddRef {
+        if value {
+            BddRef::TRUE
+        } else {
+            BddRef::FALSE
+        }
+    }
+
+    /// Gets the predicate which is `true` iff the given variable is `true`.
+    pub fn var(&mut self, var: V) -> BddRef {
+        self.mk(V::index_of(var), BddRef::FALSE, BddRef::TRUE)
+    }
+
+    /// Gets the predicate which is `true` iff the given variable is `false`.
+    pub fn not_var(&mut self, var: V) -> BddRef {
+        self.mk(V::index_of(var), BddRef::TRUE, BddRef::FALSE)
+    }
+
+    /// Gets the negation of the given predicate.
+    pub fn not(&mut self, f: BddRef) -> BddRef {
+        self.apply(Op::Xor, f, BddRef::TRUE)
+    }
+
+    /// Gets the conjunction of the given predicates.
+    pub fn and(&mut self, f: BddRef, g: BddRef) -> BddRef {
+        self.apply(Op::And, f, g)
+    }
+
+    /// Gets the disjunction of the given predicates.
+    pub fn or(&mut self, f: BddRef, g: BddRef) -> BddRef {
+        self.apply(Op::Or, f, g)
+    }
+
+    /// Gets the exclusive disjunction of the given predicates.
+    pub fn xor(&mut self, f: BddRef, g: BddRef) -> BddRef {
+        self.apply(Op::Xor, f, g)
+    }
+
+    /// Gets the predicate obtained by fixing the given variable to the given value in `f`.
+    pub fn restrict(&mut self, f: BddRef, var: V, value: bool) -> BddRef {
+        let mut memo = BTreeMap::new();
+        self.restrict_index(&mut memo, f, V::index_of(var), value)
+    }
+
+    /// Gets the predicate which is `true` iff there is some value of the given variable for
+    /// which `f` is `true`.
+    pub fn exists(&mut self, f: BddRef, var: V) -> BddRef {
+        let low = self.restrict(f, var.clone(), false);
+        let high = self.restrict(f, var, true);
+        self.or(low, high)
+    }
+
+    /// Gets the predicate which is `true` iff `f` is `true` for both values of the given
+    /// variable.
+    pub fn forall(&mut self, f: BddRef, var: V) -> BddRef {
+        let low = self.restrict(f, var.clone(), false);
+        let high = self.restrict(f, var, true);
+        self.and(low, high)
+    }
+
+    /// Evaluates the given predicate for a particular assignment of variables.
+    pub fn eval(&self, f: BddRef, mut assignment: impl FnMut(V) -> bool) -> bool {
+        let mut cur = f;
+        while !cur.is_const() {
+            let node = self.nodes[cur.0];
+            let var = unsafe { V::nth_unchecked(node.var) };
+            cur = if assignment(var) { node.high } else { node.low };
+        }
+        cur == BddRef::TRUE
+    }
+
+    /// Counts the number of assignments of all variables of type `V` for which the given
+    /// predicate is `true`.
+    ///
+    /// # Panics
+    /// Panics if the result does not fit in a [`u128`].
+    pub fn count_models(&self, f: BddRef) -> u128 {
+        let mut memo = BTreeMap::new();
+        self.count_models_from(&mut memo, f) * pow2(self.nodes[f.0].var)
+    }
+
+    /// Gets or creates the node with the given variable and children.
+    fn mk(&mut self, var: usize, low: BddRef, high: BddRef) -> BddRef {
+        if low == high {
+            return low;
+        }
+        if let Some(res) = self.unique.get(&(var, low, high)) {
+            return *res;
+        }
+        let res = BddRef(self.nodes.len());
+        self.nodes.push(Node { var, low, high });
+        self.unique.insert((var, low, high), res);
+        res
+    }
+
+    /// Applies a binary operation to a pair of predicates.
+    fn apply(&mut self, op: Op, f: BddRef, g: BddRef) -> BddRef {
+        let (f, g) = if f <= g { (f, g) } else { (g, f) };
+        match op {
+            Op::And => {
+                if f == BddRef::FALSE || f == g {
+                    return f;
+                } else if f == BddRef::TRUE {
+                    return g;
+                }
+            }
+            Op::Or => {
+                if f == BddRef::TRUE || f == g {
+                    return f;
+                } else if f == BddRef::FALSE {
+                    return g;
+                }
+            }
+            Op::Xor => {
+                if f == g {
+                    return BddRef::FALSE;
+                } else if f == BddRef::FALSE {
+                    return g;
+                }
+            }
+        }
+        if let Some(res) = self.cache.get(&(op, f, g)) {
+            return *res;
+        }
+        let f_node = self.nodes[f.0];
+        let g_node = self.nodes[g.0];
+        let var = f_node.var.min(g_node.var);
+        let (f_low, f_high) = cofactors(f, f_node, var);
+        let (g_low, g_high) = cofactors(g, g_node, var);
+        let low = self.apply(op, f_low, g_low);
+        let high = self.apply(op, f_high, g_high);
+        let res = self.mk(var, low, high);
+        self.cache.insert((op, f, g), res);
+        res
+    }
+
+    /// Implementation of [`Bdd::restrict`] in terms of variable indices.
+    fn restrict_index(
+        &mut self,
+        memo: &mut BTreeMap<BddRef, BddRef>,
+        f: BddRef,
+        var: usize,
+        value: bool,
+    ) -> BddRef {
+        let node = self.nodes[f.0];
+        if node.var > var {
+            return f;
+        } else if node.var == var {
+            return if value { node.high } else { node.low };
+        }
+        if let Some(res) = memo.get(&f) {
+            return *res;
+        }
+        let low = self.restrict_index(memo, node.low, var, value);
+        let high = self.restrict_index(memo, node.high, var, value);
+        let res = self.mk(node.var, low, high);
+        memo.insert(f, res);
+        res
+    }
+
+    /// Counts the number of assignments of variables starting at the variable for `f` for which
+    /// `f` is `true`.
+    fn count_models_from(&self, memo: &mut BTreeMap<BddRef, u128>, f: BddRef) -> u128 {
+        if f.is_const() {
+            return (f == BddRef::TRUE) as u128;
+        }
+        if let Some(res) = memo.get(&f) {
+            return *res;
+        }
+        let node = self.nodes[f.0];
+        let low_skip = self.nodes[node.low.0].var - node.var - 1;
+        let high_skip = self.nodes[node.high.0].var - node.var - 1;
+        let res = self.count_models_from(memo, node.low) * pow2(low_skip)
+            + self.count_models_from(memo, node.high) * pow2(high_skip);
+        memo.insert(f, res);
+        res
+    }
+}
+
+impl<V: BitmapFinite> Bdd<V> {
+    /// Gets the predicate which is `true` for exactly one assignment of variables: the one where
+    /// the variables in the given set are `true` and all others are `false`.
+    pub fn from_set(&mut self, set: BitmapSet<V>) -> BddRef {
+        let mut res = BddRef::TRUE;
+        for index in (0..V::COUNT).rev() {
+            let var = unsafe { V::nth_unchecked(index) };
+            res = if set.contains(var) {
+                self.mk(index, BddRef::FALSE, res)
+            } else {
+                self.mk(index, res, BddRef::FALSE)
+            };
+        }
+        res
+    }
+
+    /// Iterates over all assignments of variables for which the given predicate is `true`,
+    /// represented as the set of variables which are `true`. This evaluates the predicate for
+    /// every possible assignment, so it is only practical for small variable types.
+    pub fn models(&self, f: BddRef) -> impl Iterator<Item = BitmapSet<V>> + '_ {
+        BitmapSet::<V>::iter().filter(move |set| self.eval(f, |v| set.contains(v)))
+    }
+}
+
+impl<V: Finite> Default for Bdd<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gets the low and high cofactors of a predicate with respect to the variable with the given
+/// index, assuming that no lesser variable appears in the predicate.
+fn cofactors(f: BddRef, node: Node, var: usize) -> (BddRef, BddRef) {
+    if node.var == var {
+        (node.low, node.high)
+    } else {
+        (f, f)
+    }
+}
+
+/// Computes `2 ^ n`.
+fn pow2(n: usize) -> u128 {
+    1u128.checked_shl(n as u32).expect("model count overflow")
+}
+
+#[test]
+fn test_bdd() {
+    let mut bdd = Bdd::<Option<bool>>::new();
+    let a = bdd.var(None);
+    let b = bdd.var(Some(false));
+    let c = bdd.var(Some(true));
+    let a_or_b = bdd.or(a, b);
+    let f = bdd.and(a_or_b, c);
+    assert_eq!(bdd.count_models(f), 3);
+    assert_eq!(bdd.count_models(BddRef::TRUE), 8);
+    assert_eq!(bdd.count_models(c), 4);
+
+    // Canonicity
+    let b_or_a = bdd.or(b, a);
+    let g = bdd.and(c, b_or_a);
+    assert_eq!(f, g);
+    let not_f = bdd.not(f);
+    assert_eq!(bdd.not(not_f), f);
+    assert_eq!(bdd.and(f, not_f), BddRef::FALSE);
+
+    // Quantification
+    let h = bdd.exists(f, None);
+    assert_eq!(h, c);
+    assert_eq!(bdd.forall(f, None), bdd.and(b, c));
+}
+
+#[test]
+fn test_bdd_set() {
+    let mut bdd = Bdd::<bool>::new();
+    let x = bdd.var(true);
+    let models: alloc::vec::Vec<_> = bdd.models(x).collect();
+    assert_eq!(models, [BitmapSet::only(true), BitmapSet::all()]);
+    let set = BitmapSet::only(false);
+    let f = bdd.from_set(set);
+    assert_eq!(bdd.count_models(f), 1);
+    assert!(bdd.eval(f, |v| set.contains(v)));
+}
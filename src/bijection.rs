@@ -0,0 +1,143 @@
+use crate::*;
+
+/// A validated 1-to-1 correspondence between the values of type `A` and the values of type `B`,
+/// stored as a pair of [`ArrayMap`]s so that it can be efficiently applied in both directions.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades
+/// }
+///
+/// let rotate = Bijection::new(|x: Suit| Suit::nth((Suit::index_of(x) + 1) % 4).unwrap()).unwrap();
+/// assert_eq!(rotate.apply(Suit::Spades), Suit::Clubs);
+/// assert_eq!(rotate.apply_inverse(Suit::Clubs), Suit::Spades);
+/// assert!(Bijection::new(|_: Suit| Suit::Hearts).is_none());
+/// ```
+pub struct Bijection<A: ArrayFinite<B>, B: ArrayFinite<A>> {
+    forward: ArrayMap<A, B>,
+    inverse: ArrayMap<B, A>,
+}
+
+impl<A: ArrayFinite<B>, B: ArrayFinite<A>> Bijection<A, B> {
+    /// Constructs a [`Bijection`] from the given function, or returns [`None`] if the function is
+    /// not a bijection.
+    pub fn new(f: impl FnMut(A) -> B) -> Option<Self> {
+        Self::from_map(ArrayMap::new(f))
+    }
+
+    /// Constructs a [`Bijection`] from the given map, or returns [`None`] if the map is not a
+    /// bijection.
+    pub fn from_map(forward: ArrayMap<A, B>) -> Option<Self> {
+        if A::COUNT != B::COUNT {
+            return None;
+        }
+
+        // Since both types have the same number of values, it suffices to check surjectivity
+        let mut inverse = ArrayMap::new(|_| A::nth(0).unwrap());
+        for a in A::iter() {
+            let b = forward[a.clone()].clone();
+            inverse[b] = a;
+        }
+        for b in B::iter() {
+            if forward[inverse[b.clone()].clone()] != b {
+                return None;
+            }
+        }
+        Some(Self { forward, inverse })
+    }
+
+    /// Gets the value of type `B` that corresponds to the given value of type `A`.
+    pub fn apply(&self, value: A) -> B {
+        self.forward[value].clone()
+    }
+
+    /// Gets the value of type `A` that corresponds to the given value of type `B`.
+    pub fn apply_inverse(&self, value: B) -> A {
+        self.inverse[value].clone()
+    }
+
+    /// Gets the inverse of this bijection.
+    pub fn invert(self) -> Bijection<B, A> {
+        Bijection {
+            forward: self.inverse,
+            inverse: self.forward,
+        }
+    }
+
+    /// Gets the bijection which applies this bijection, followed by the given bijection.
+    pub fn then<C: ArrayFinite<A> + ArrayFinite<B>>(
+        &self,
+        other: &Bijection<B, C>,
+    ) -> Bijection<A, C>
+    where
+        A: ArrayFinite<C>,
+        B: ArrayFinite<C>,
+    {
+        Bijection {
+            forward: self.forward.map(|b| other.apply(b.clone())),
+            inverse: other.inverse.map(|b| self.apply_inverse(b.clone())),
+        }
+    }
+
+    /// Gets the map from values of type `A` to values of type `B`.
+    pub fn forward(&self) -> &ArrayMap<A, B> {
+        &self.forward
+    }
+
+    /// Gets the map from values of type `B` to values of type `A`.
+    pub fn inverse(&self) -> &ArrayMap<B, A> {
+        &self.inverse
+    }
+}
+
+impl<A: ArrayFinite<A>> Bijection<A, A> {
+    /// The bijection which maps every value to itself.
+    pub fn identity() -> Self {
+        Self {
+            forward: ArrayMap::new(|a| a),
+            inverse: ArrayMap::new(|a| a),
+        }
+    }
+}
+
+impl<A: ArrayFinite<B>, B: ArrayFinite<A>> Clone for Bijection<A, B>
+where
+    ArrayMap<A, B>: Clone,
+    ArrayMap<B, A>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            forward: self.forward.clone(),
+            inverse: self.inverse.clone(),
+        }
+    }
+}
+
+impl<A: ArrayFinite<B>, B: ArrayFinite<A>> PartialEq for Bijection<A, B>
+where
+    ArrayMap<A, B>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.forward == other.forward
+    }
+}
+
+impl<A: ArrayFinite<B>, B: ArrayFinite<A>> Eq for Bijection<A, B> where ArrayMap<A, B>: Eq {}
+
+#[test]
+fn test_bijection() {
+    let not = Bijection::new(|x: bool| !x).unwrap();
+    assert!(not.apply(false));
+    assert!(not.apply_inverse(false));
+    assert!(not.then(&not) == Bijection::identity());
+    assert!(not.clone().invert() == not);
+    assert!(Bijection::new(|_: bool| true).is_none());
+    assert!(Bijection::new(|x: bool| x as u8).is_none());
+}
@@ -44,9 +44,19 @@ impl<T: CompressFinite> Compress<T> {
         Compress(T::Index::from_usize_unchecked(T::index_of(value)))
     }
 
+    /// Constructs a compressed value directly from its index according to [`Finite::index_of`],
+    /// or returns a [`TryFromIndexError`] if the index is out of bounds.
+    pub fn try_from_index(index: usize) -> Result<Self, TryFromIndexError> {
+        if index < T::COUNT {
+            Ok(Compress(T::Index::from_usize_unchecked(index)))
+        } else {
+            Err(TryFromIndexError::new(index, T::COUNT))
+        }
+    }
+
     /// Gets the expanded form of this compressed value.
     pub fn expand(&self) -> T {
-        unsafe { T::nth(self.0.to_usize()).unwrap_unchecked() }
+        unsafe { T::nth_unchecked(self.0.to_usize()) }
     }
 }
 
@@ -79,7 +89,20 @@ impl<T: CompressFinite> Clone for Compress<T> {
 
 impl<T: CompressFinite> Copy for Compress<T> {}
 
+impl<T: CompressFinite> core::hash::Hash for Compress<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
 #[test]
 fn test_compress_zst() {
     assert_eq!(core::mem::size_of::<()>(), 0);
 }
+
+#[test]
+fn test_try_from_index() {
+    assert!(Compress::<bool>::try_from_index(1).ok().unwrap().expand());
+    let err = Compress::<bool>::try_from_index(2).err().unwrap();
+    assert_eq!(err, TryFromIndexError::new(2, 2));
+}
@@ -0,0 +1,172 @@
+use crate::*;
+use core::ops::Index;
+
+/// Counts the number of occurrences of each value of type `T`, implemented using an
+/// [`ArrayMap`] from values to counts.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Event {
+///     Click,
+///     Scroll,
+///     Key,
+/// }
+///
+/// let counter: Counter<Event> = [Event::Key, Event::Click, Event::Key].into_iter().collect();
+/// assert_eq!(counter[Event::Key], 2);
+/// assert_eq!(counter[Event::Scroll], 0);
+/// assert_eq!(counter.total(), 3);
+/// assert!(counter.most_common(2).eq([(Event::Key, 2), (Event::Click, 1)]));
+/// ```
+pub struct Counter<T: ArrayFinite<usize>>(ArrayMap<T, usize>);
+
+impl<T: ArrayFinite<usize>> Counter<T> {
+    /// Constructs a new [`Counter`] where every value has a count of zero.
+    pub fn new() -> Self {
+        Counter(ArrayMap::default())
+    }
+
+    /// Increments the count for the given value.
+    pub fn increment(&mut self, value: T) {
+        self.0[value] += 1;
+    }
+
+    /// Adds `n` to the count for the given value.
+    pub fn add(&mut self, value: T, n: usize) {
+        self.0[value] += n;
+    }
+
+    /// Decrements the count for the given value, unless it is already zero.
+    pub fn decrement_saturating(&mut self, value: T) {
+        let count = &mut self.0[value];
+        *count = count.saturating_sub(1);
+    }
+
+    /// The sum of the counts for all values.
+    pub fn total(&self) -> usize {
+        self.0.values().sum()
+    }
+
+    /// Iterates over the `n` values with the highest counts, along with their counts, in
+    /// decreasing order of count. Values with the same count are produced in increasing order.
+    /// This does not allocate, but takes `O(T::COUNT)` time for each value produced.
+    pub fn most_common(&self, n: usize) -> impl Iterator<Item = (T, usize)> + '_ {
+        let counts = self.0.as_slice();
+        let mut last: Option<(usize, usize)> = None;
+        core::iter::from_fn(move || {
+            // Find the entry which comes next after `last` in order of decreasing count, then
+            // increasing index
+            let mut best: Option<(usize, usize)> = None;
+            for (index, &count) in counts.iter().enumerate() {
+                let after_last = match last {
+                    Some((l_count, l_index)) => {
+                        count < l_count || (count == l_count && index > l_index)
+                    }
+                    None => true,
+                };
+                let beats_best = match best {
+                    Some((b_count, _)) => count > b_count,
+                    None => true,
+                };
+                if after_last && beats_best {
+                    best = Some((count, index));
+                }
+            }
+            last = best;
+            best.map(|(count, index)| (unsafe { T::nth_unchecked(index) }, count))
+        })
+        .take(n)
+    }
+
+    /// Iterates over all values along with their counts, in order of value index.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (T, usize)> + ExactSizeIterator + '_ {
+        self.0.iter().map(|(value, count)| (value, *count))
+    }
+
+    /// Gets the counts for all values as an [`ArrayMap`].
+    pub fn as_map(&self) -> &ArrayMap<T, usize> {
+        &self.0
+    }
+
+    /// Converts this [`Counter`] into an [`ArrayMap`] of counts.
+    pub fn into_map(self) -> ArrayMap<T, usize> {
+        self.0
+    }
+}
+
+impl<T: ArrayFinite<usize>> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ArrayFinite<usize>> Index<T> for Counter<T> {
+    type Output = usize;
+    fn index(&self, index: T) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T: ArrayFinite<usize>> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.increment(value);
+        }
+    }
+}
+
+impl<T: ArrayFinite<usize>> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: ArrayFinite<usize>> Clone for Counter<T>
+where
+    T::Array: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ArrayFinite<usize>> Copy for Counter<T> where T::Array: Copy {}
+
+impl<T: ArrayFinite<usize>> PartialEq for Counter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T: ArrayFinite<usize>> Eq for Counter<T> {}
+
+impl<T: core::fmt::Debug + ArrayFinite<usize>> core::fmt::Debug for Counter<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[test]
+fn test_counter() {
+    let mut counter: Counter<u8> = [5, 3, 5, 200, 3, 5].into_iter().collect();
+    assert_eq!(counter.total(), 6);
+    assert!(counter
+        .most_common(4)
+        .eq([(5, 3), (3, 2), (200, 1), (0, 0)]));
+    counter.decrement_saturating(5);
+    counter.decrement_saturating(5);
+    counter.decrement_saturating(7);
+    assert_eq!(counter[7], 0);
+    assert!(counter.most_common(3).eq([(3, 2), (5, 1), (200, 1)]));
+    counter.add(9, 10);
+    counter.extend([9]);
+    assert_eq!(counter.most_common(1).next(), Some((9, 11)));
+    assert_eq!(counter.most_common(1000).count(), 256);
+    assert_eq!(counter.total(), 15);
+    assert_eq!(counter.into_map()[9], 11);
+}
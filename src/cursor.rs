@@ -0,0 +1,96 @@
+use crate::*;
+use core::marker::PhantomData;
+
+/// A resumable position in the enumeration of the values of a [`Finite`] type, which yields values
+/// in fixed-size chunks. The position is just an index, so it can be saved and later restored
+/// using [`Cursor::resume`], e.g. to checkpoint a long exhaustive search or to split one across
+/// multiple processes.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let mut cursor = Cursor::<u16>::new();
+/// let chunk = cursor.next_chunk(1000);
+/// assert_eq!(chunk.len(), 1000);
+/// let position = cursor.position();
+/// assert_eq!(position, 1000);
+///
+/// // Later...
+/// let mut cursor = Cursor::<u16>::resume(position);
+/// assert_eq!(cursor.next_chunk(1000).next(), Some(1000));
+/// ```
+pub struct Cursor<T: Finite> {
+    position: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Finite> Cursor<T> {
+    /// Constructs a [`Cursor`] at the start of the enumeration.
+    pub fn new() -> Self {
+        Self::resume(0)
+    }
+
+    /// Constructs a [`Cursor`] at the given position, as previously returned by
+    /// [`Cursor::position`]. Positions past the end of the enumeration are clamped.
+    pub fn resume(position: usize) -> Self {
+        Self {
+            position: position.min(T::COUNT),
+            marker: PhantomData,
+        }
+    }
+
+    /// The index of the next value this cursor will yield.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The number of values this cursor has yet to yield.
+    pub fn remaining(&self) -> usize {
+        T::COUNT - self.position
+    }
+
+    /// Indicates whether this cursor has yielded all values.
+    pub fn is_done(&self) -> bool {
+        self.position == T::COUNT
+    }
+
+    /// Gets an iterator over the next `size` values (or fewer, at the end of the enumeration) and
+    /// advances the cursor past them.
+    pub fn next_chunk(&mut self, size: usize) -> FiniteIter<T> {
+        let index = self.position;
+        self.position = index.saturating_add(size).min(T::COUNT);
+        FiniteIter {
+            index,
+            end: self.position,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Finite> Default for Cursor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Finite> Clone for Cursor<T> {
+    fn clone(&self) -> Self {
+        Self::resume(self.position)
+    }
+}
+
+#[test]
+fn test_cursor() {
+    let mut cursor = Cursor::<u8>::new();
+    let mut total = 0;
+    while !cursor.is_done() {
+        let chunk = cursor.next_chunk(100);
+        assert!(chunk.len() <= 100);
+        total += chunk.count();
+    }
+    assert_eq!(total, 256);
+    assert_eq!(cursor.remaining(), 0);
+    assert_eq!(cursor.next_chunk(100).next(), None);
+    assert!(Cursor::<u8>::resume(1000).is_done());
+    assert_eq!(Cursor::<u8>::resume(250).next_chunk(100).len(), 6);
+}
@@ -0,0 +1,447 @@
+use crate::uint::select_u64;
+use crate::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A set of values of type `T`, implemented using a heap-allocated bitmap. Requires the `alloc`
+/// feature.
+///
+/// Unlike [`BitmapSet`], this can be used for any [`Finite`] type, including generic ones and
+/// those with too many values for a fixed-size bitmap. For repeated [`rank`](Self::rank) and
+/// [`select`](Self::select) queries on large sets, use [`DynBitmapSet::indexed`].
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let mut set = DynBitmapSet::<u16>::none();
+/// set.include(1000);
+/// set.include(5);
+/// assert_eq!(set.size(), 2);
+/// assert_eq!(set.rank(1000), 1);
+/// assert_eq!(set.select(0), Some(5));
+/// assert!(set.iter().eq([5, 1000]));
+/// ```
+pub struct DynBitmapSet<T: Finite>(Vec<u64>, PhantomData<T>);
+
+impl<T: Finite> DynBitmapSet<T> {
+    /// Constructs a new [`DynBitmapSet`] with initial membership determined using the given
+    /// function.
+    pub fn new(mut f: impl FnMut(T) -> bool) -> Self {
+        let mut set = Self::none();
+        for (i, value) in T::iter().enumerate() {
+            if f(value) {
+                set.0[i / 64] |= 1 << (i % 64);
+            }
+        }
+        set
+    }
+
+    /// The set of all possible values of `T`.
+    pub fn all() -> Self {
+        let mut words = alloc::vec![!0u64; T::COUNT / 64];
+        if T::COUNT % 64 > 0 {
+            words.push((1 << (T::COUNT % 64)) - 1);
+        }
+        DynBitmapSet(words, PhantomData)
+    }
+
+    /// The empty set.
+    pub fn none() -> Self {
+        DynBitmapSet(alloc::vec![0; T::COUNT.div_ceil(64)], PhantomData)
+    }
+
+    /// The set consisting of only the given value.
+    pub fn only(value: T) -> Self {
+        let mut set = Self::none();
+        set.include(value);
+        set
+    }
+
+    /// The number of values in this set.
+    pub fn size(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Determines whether this is the empty set.
+    pub fn is_none(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+
+    /// Determines whether this is the empty set. This is equivalent to [`DynBitmapSet::is_none`].
+    pub fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    /// Determines whether this is the set of all possible values of `T`.
+    pub fn is_all(&self) -> bool {
+        self.size() == T::COUNT
+    }
+
+    /// The number of values in this set. This is equivalent to [`DynBitmapSet::size`].
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Removes all values from this set.
+    pub fn clear(&mut self) {
+        self.0.fill(0);
+    }
+
+    /// Adds all values in `other` to this set.
+    pub fn union_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Removes all values from this set which are not in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Removes all values from this set which are in `other`.
+    pub fn difference_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// The number of values in this set which are less than the given value. This takes time
+    /// proportional to the number of values of `T`; see [`DynBitmapSet::indexed`] for a
+    /// constant-time alternative.
+    pub fn rank(&self, value: T) -> usize {
+        let index = T::index_of(value);
+        let (word, bit) = (index / 64, index % 64);
+        let below: usize = self.0[..word]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+        below + (self.0[word] & ((1 << bit) - 1)).count_ones() as usize
+    }
+
+    /// Gets the value in this set which has exactly `n` smaller values in the set, or returns
+    /// [`None`] if the set does not have more than `n` values. This is the inverse of
+    /// [`DynBitmapSet::rank`]. This takes time proportional to the number of values of `T`; see
+    /// [`DynBitmapSet::indexed`] for a faster alternative.
+    pub fn select(&self, mut n: usize) -> Option<T> {
+        for (i, word) in self.0.iter().enumerate() {
+            let count = word.count_ones() as usize;
+            if n < count {
+                let index = i * 64 + select_u64(*word, n)?;
+                return Some(unsafe { T::nth_unchecked(index) });
+            }
+            n -= count;
+        }
+        None
+    }
+
+    /// Iterates over the values in this set, in increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(i, word)| WordBits {
+                word: *word,
+                base: i * 64,
+            })
+            .map(|index| unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Builds a rank/select index for this set, allowing [`rank`](IndexedBitmapSet::rank) queries
+    /// to be answered in constant time, and [`select`](IndexedBitmapSet::select) queries to be
+    /// answered in near-constant time. The index uses at most half a bit of additional memory for
+    /// each value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = DynBitmapSet::new(|x: u16| x % 7 == 3).indexed();
+    /// assert_eq!(set.rank(10003), 1429);
+    /// assert_eq!(set.select(1429), Some(10006));
+    /// ```
+    pub fn indexed(self) -> IndexedBitmapSet<T> {
+        IndexedBitmapSet::new(self)
+    }
+
+    /// Applies the given binary operation to corresponding words of this set and `other`.
+    fn zip_with(&mut self, other: &Self, mut f: impl FnMut(u64, u64) -> u64) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+/// An iterator over the positions of the one bits in a word.
+struct WordBits {
+    word: u64,
+    base: usize,
+}
+
+impl Iterator for WordBits {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.word == 0 {
+            return None;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.base + bit)
+    }
+}
+
+impl DoubleEndedIterator for WordBits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.word == 0 {
+            return None;
+        }
+        let bit = 63 - self.word.leading_zeros() as usize;
+        self.word &= !(1 << bit);
+        Some(self.base + bit)
+    }
+}
+
+impl<T: Finite> Default for DynBitmapSet<T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: Finite> Set<T> for DynBitmapSet<T> {
+    fn contains(&self, value: T) -> bool {
+        let index = T::index_of(value);
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    fn include(&mut self, value: T) {
+        let index = T::index_of(value);
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    fn exclude(&mut self, value: T) {
+        let index = T::index_of(value);
+        self.0[index / 64] &= !(1 << (index % 64));
+    }
+
+    fn is_empty(&self) -> bool {
+        DynBitmapSet::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        DynBitmapSet::len(self)
+    }
+
+    fn clear(&mut self) {
+        DynBitmapSet::clear(self)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        DynBitmapSet::union_with(self, other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        DynBitmapSet::intersect_with(self, other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        DynBitmapSet::difference_with(self, other)
+    }
+}
+
+impl<T: Finite> FromIterator<T> for DynBitmapSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::none();
+        for value in iter {
+            set.include(value);
+        }
+        set
+    }
+}
+
+impl<T: Finite> Clone for DynBitmapSet<T> {
+    fn clone(&self) -> Self {
+        DynBitmapSet(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Finite> PartialEq for DynBitmapSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Finite> Eq for DynBitmapSet<T> {}
+
+impl<T: Finite> core::hash::Hash for DynBitmapSet<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: core::fmt::Debug + Finite> core::fmt::Debug for DynBitmapSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The number of words in a superblock of an [`IndexedBitmapSet`].
+const SUPERBLOCK_WORDS: usize = 8;
+
+/// The number of values in the set between consecutive select samples of an
+/// [`IndexedBitmapSet`].
+const SELECT_SAMPLE_RATE: usize = SUPERBLOCK_WORDS * 64;
+
+/// An immutable [`DynBitmapSet`] augmented with a succinct rank/select index, as constructed by
+/// [`DynBitmapSet::indexed`].
+///
+/// The index consists of:
+///  * For every superblock of 512 bits, the number of values in the set before it.
+///  * For every 64-bit block, the number of values in the set before it within its superblock.
+///  * For every 512th value in the set, the superblock containing it.
+pub struct IndexedBitmapSet<T: Finite> {
+    set: DynBitmapSet<T>,
+    superblocks: Vec<usize>,
+    blocks: Vec<u16>,
+    samples: Vec<usize>,
+}
+
+impl<T: Finite> IndexedBitmapSet<T> {
+    /// Builds the index for the given set.
+    fn new(set: DynBitmapSet<T>) -> Self {
+        let words = &set.0;
+        let mut superblocks = Vec::with_capacity(words.len().div_ceil(SUPERBLOCK_WORDS) + 1);
+        let mut blocks = Vec::with_capacity(words.len());
+        let mut samples = Vec::new();
+        let mut total = 0;
+        for (i, chunk) in words.chunks(SUPERBLOCK_WORDS).enumerate() {
+            superblocks.push(total);
+            let mut within = 0;
+            for word in chunk {
+                blocks.push(within as u16);
+                within += word.count_ones() as usize;
+            }
+            // Record the superblock for each sampled value within this superblock
+            while samples.len() * SELECT_SAMPLE_RATE < total + within {
+                samples.push(i);
+            }
+            total += within;
+        }
+        superblocks.push(total);
+        IndexedBitmapSet {
+            set,
+            superblocks,
+            blocks,
+            samples,
+        }
+    }
+
+    /// Determines whether the set contains the given value.
+    pub fn contains(&self, value: T) -> bool {
+        self.set.contains(value)
+    }
+
+    /// The number of values in this set.
+    pub fn size(&self) -> usize {
+        *self.superblocks.last().unwrap()
+    }
+
+    /// The number of values in this set which are less than the given value. This takes constant
+    /// time.
+    pub fn rank(&self, value: T) -> usize {
+        let index = T::index_of(value);
+        let (word, bit) = (index / 64, index % 64);
+        self.superblocks[word / SUPERBLOCK_WORDS]
+            + self.blocks[word] as usize
+            + (self.set.0[word] & ((1 << bit) - 1)).count_ones() as usize
+    }
+
+    /// Gets the value in this set which has exactly `n` smaller values in the set, or returns
+    /// [`None`] if the set does not have more than `n` values. This is the inverse of
+    /// [`IndexedBitmapSet::rank`].
+    ///
+    /// This takes constant time when values are spread evenly throughout the set. Otherwise, it
+    /// is at worst logarithmic in the number of superblocks between consecutive select samples.
+    pub fn select(&self, n: usize) -> Option<T> {
+        if n >= self.size() {
+            return None;
+        }
+
+        // Find the last superblock with at most `n` values before it, narrowing the search using
+        // the samples
+        let sample = n / SELECT_SAMPLE_RATE;
+        let lo = self.samples[sample];
+        let hi = self
+            .samples
+            .get(sample + 1)
+            .map_or(self.superblocks.len() - 1, |hi| hi + 1);
+        let superblock = lo + self.superblocks[lo..hi].partition_point(|count| *count <= n) - 1;
+
+        // Find the word within the superblock
+        let mut n = n - self.superblocks[superblock];
+        let start = superblock * SUPERBLOCK_WORDS;
+        let end = (start + SUPERBLOCK_WORDS).min(self.blocks.len());
+        let word =
+            start + self.blocks[start..end].partition_point(|count| *count as usize <= n) - 1;
+        n -= self.blocks[word] as usize;
+        let index = word * 64 + select_u64(self.set.0[word], n)?;
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Iterates over the values in this set, in increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.set.iter()
+    }
+
+    /// Discards the index, returning the underlying set so that it can be modified.
+    pub fn into_inner(self) -> DynBitmapSet<T> {
+        self.set
+    }
+}
+
+impl<T: core::fmt::Debug + Finite> core::fmt::Debug for IndexedBitmapSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.set.fmt(f)
+    }
+}
+
+#[test]
+fn test_dyn_bitmap_set() {
+    use core::cmp::Ordering;
+    let a = DynBitmapSet::new(|x: u16| x % 3 == 0);
+    let b: DynBitmapSet<u16> = (0..1000).collect();
+    assert_eq!(a.size(), 21846);
+    assert!(DynBitmapSet::<u16>::all().is_all());
+    assert!(DynBitmapSet::<Ordering>::all()
+        .iter()
+        .rev()
+        .eq(Ordering::iter().rev()));
+    let mut c = a.clone();
+    c.intersect_with(&b);
+    assert!(c.iter().eq((0..1000).step_by(3)));
+    assert!(c.iter().rev().eq((0..1000).step_by(3).rev()));
+    c.union_with(&DynBitmapSet::only(1001));
+    c.difference_with(&DynBitmapSet::only(0));
+    assert_eq!(c.len(), 334);
+    assert_eq!(c.rank(1001), 333);
+    assert_eq!(c.select(333), Some(1001));
+    assert_eq!(c.select(334), None);
+    c.clear();
+    assert!(c.is_empty());
+}
+
+#[test]
+fn test_indexed_bitmap_set() {
+    // A set with dense and sparse regions
+    let set = DynBitmapSet::new(|x: u16| x < 3000 || x % 4001 == 0 || x > 60000);
+    let values: Vec<u16> = set.iter().collect();
+    let indexed = set.clone().indexed();
+    assert_eq!(indexed.size(), values.len());
+    for (n, value) in values.iter().enumerate() {
+        assert_eq!(indexed.select(n), Some(*value));
+        assert_eq!(indexed.rank(*value), n);
+        assert_eq!(set.rank(*value), n);
+        assert_eq!(set.select(n), Some(*value));
+    }
+    assert_eq!(indexed.select(values.len()), None);
+    assert_eq!(indexed.rank(40000), set.rank(40000));
+    assert!(indexed.contains(4001) && !indexed.contains(4002));
+    let empty = DynBitmapSet::<u8>::none().indexed();
+    assert_eq!(empty.select(0), None);
+    assert_eq!(empty.rank(255), 0);
+    assert_eq!(indexed.into_inner(), set);
+}
@@ -0,0 +1,135 @@
+use core::fmt;
+
+/// The error returned when trying to get the value for an index which is out of bounds.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let err = bool::try_nth(5).unwrap_err();
+/// assert_eq!(err.index(), 5);
+/// assert_eq!(err.count(), 2);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct TryFromIndexError {
+    index: usize,
+    count: usize,
+}
+
+impl TryFromIndexError {
+    /// Constructs a new [`TryFromIndexError`] for the given index, which was out of bounds for a
+    /// type with `count` values.
+    pub fn new(index: usize, count: usize) -> Self {
+        Self { index, count }
+    }
+
+    /// The index which was out of bounds.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The number of values of the type the index was for.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl fmt::Display for TryFromIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a type with {} values",
+            self.index, self.count
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIndexError {}
+
+/// The error returned when parsing a value from a variant name which doesn't match any variant.
+/// This is used by the `FromStr` implementation generated by `#[finite(names)]`.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct ParseNameError;
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "name does not match any variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNameError {}
+
+/// Describes a problem with a [`Finite`](crate::Finite) implementation, as found by
+/// [`validate`](crate::validate).
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ValidationError {
+    /// `nth` returned `None` for an index less than `COUNT`.
+    MissingValue {
+        /// The index given to `nth`.
+        index: usize,
+    },
+
+    /// `index_of` returned the wrong index for a value produced by `nth`.
+    WrongIndex {
+        /// The index given to `nth`.
+        index: usize,
+
+        /// The index returned by `index_of`.
+        actual: usize,
+    },
+
+    /// The value produced by `nth` for an index was not greater than the value produced for the
+    /// previous index.
+    OutOfOrder {
+        /// The index given to `nth`.
+        index: usize,
+    },
+
+    /// `nth` returned a value for an index which is not less than `COUNT`.
+    ExtraValue {
+        /// The index given to `nth`.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::MissingValue { index } => write!(f, "nth({}) is None", index),
+            ValidationError::WrongIndex { index, actual } => {
+                write!(f, "index_of(nth({})) is {}", index, actual)
+            }
+            ValidationError::OutOfOrder { index } => {
+                write!(f, "nth({}) is not greater than nth({})", index, index - 1)
+            }
+            ValidationError::ExtraValue { index } => write!(f, "nth({}) is not None", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// The error returned by [`ArrayMap::try_from_iter`](crate::ArrayMap::try_from_iter) when the
+/// given entries don't specify exactly one value for each key.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum MapFromIterError<K> {
+    /// No value was given for the key.
+    MissingKey(K),
+
+    /// More than one value was given for the key.
+    DuplicateKey(K),
+}
+
+impl<K: fmt::Debug> fmt::Display for MapFromIterError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapFromIterError::MissingKey(key) => write!(f, "missing value for key {:?}", key),
+            MapFromIterError::DuplicateKey(key) => write!(f, "duplicate value for key {:?}", key),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: fmt::Debug> std::error::Error for MapFromIterError<K> {}
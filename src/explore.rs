@@ -0,0 +1,146 @@
+use crate::*;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// The set of states reachable from an initial state, as computed by [`explore`] or
+/// [`check_invariant`]. Along with membership, this records how each state was first reached, so
+/// that a shortest path to any reachable state can be recovered.
+pub struct Reachable<S: ArrayFinite<Option<S>>> {
+    /// For each state, the state it was first reached from, or [`None`] if it isn't reachable.
+    /// The initial state is its own parent.
+    parents: ArrayMap<S, Option<S>>,
+    size: usize,
+}
+
+impl<S: ArrayFinite<Option<S>>> Reachable<S> {
+    /// Determines whether the given state is reachable.
+    pub fn contains(&self, state: S) -> bool {
+        self.parents[state].is_some()
+    }
+
+    /// The number of reachable states.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Iterates over all reachable states, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = S> + '_ {
+        S::iter().filter(move |state| self.contains(state.clone()))
+    }
+
+    /// Gets a shortest path from the initial state to the given state, including both endpoints,
+    /// or returns [`None`] if the state isn't reachable.
+    pub fn path_to(&self, state: S) -> Option<Vec<S>> {
+        let mut path = Vec::new();
+        let mut parent = self.parents[state.clone()].clone()?;
+        path.push(state);
+        while parent != *path.last().unwrap() {
+            path.push(parent.clone());
+            parent = self.parents[parent].clone().unwrap();
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<S: core::fmt::Debug + ArrayFinite<Option<S>>> core::fmt::Debug for Reachable<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Computes the set of states reachable from `init` by repeatedly applying `succ`, which gives the
+/// states directly reachable from a state.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let reach = explore(0u8, |x| [x.wrapping_add(6)]);
+/// assert_eq!(reach.size(), 128);
+/// assert_eq!(reach.path_to(18), Some(vec![0, 6, 12, 18]));
+/// assert!(!reach.contains(3));
+/// ```
+pub fn explore<S: ArrayFinite<Option<S>>, I: IntoIterator<Item = S>>(
+    init: S,
+    succ: impl FnMut(&S) -> I,
+) -> Reachable<S> {
+    match check_invariant(init, succ, |_| true) {
+        Ok(reach) => reach,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Explores the states reachable from `init` by repeatedly applying `succ`, checking that
+/// `invariant` holds for each of them. If it doesn't, returns a shortest path from `init` to a
+/// state which violates the invariant. States are explored in breadth-first order, so no state is
+/// visited more than once.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Counters(bool, bool, bool);
+///
+/// // Each step sets one bit, but only if the bit before it is already set
+/// let succ = |&Counters(a, b, c): &Counters| {
+///     [Counters(true, b, c), Counters(a, a, c), Counters(a, b, b)]
+/// };
+/// let init = Counters(false, false, false);
+/// assert!(check_invariant(init, succ, |&Counters(a, _, c)| a || !c).is_ok());
+/// assert_eq!(
+///     check_invariant(init, succ, |&Counters(_, _, c)| !c).unwrap_err(),
+///     [
+///         Counters(false, false, false),
+///         Counters(true, false, false),
+///         Counters(true, true, false),
+///         Counters(true, true, true)
+///     ]
+/// );
+/// ```
+pub fn check_invariant<S: ArrayFinite<Option<S>>, I: IntoIterator<Item = S>>(
+    init: S,
+    mut succ: impl FnMut(&S) -> I,
+    mut invariant: impl FnMut(&S) -> bool,
+) -> Result<Reachable<S>, Vec<S>> {
+    let mut reach = Reachable {
+        parents: ArrayMap::new(|_| None),
+        size: 1,
+    };
+    reach.parents[init.clone()] = Some(init.clone());
+    let mut queue = VecDeque::new();
+    queue.push_back(init);
+    while let Some(state) = queue.pop_front() {
+        if !invariant(&state) {
+            return Err(reach.path_to(state).unwrap());
+        }
+        for next in succ(&state) {
+            let parent = &mut reach.parents[next.clone()];
+            if parent.is_none() {
+                *parent = Some(state.clone());
+                reach.size += 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    Ok(reach)
+}
+
+#[test]
+fn test_explore() {
+    let succ = |x: &u8| [x.wrapping_mul(3), x.wrapping_add(10)];
+    let reach = explore(1u8, succ);
+    assert_eq!(reach.iter().count(), reach.size());
+    for x in reach.iter() {
+        let path = reach.path_to(x).unwrap();
+        assert_eq!(path.first(), Some(&1));
+        assert_eq!(path.last(), Some(&x));
+        assert!(path.windows(2).all(|w| succ(&w[0]).contains(&w[1])));
+    }
+    assert_eq!(reach.path_to(1), Some(alloc::vec![1]));
+    assert_eq!(
+        check_invariant(1u8, succ, |x| *x != 13).unwrap_err(),
+        [1, 3, 13]
+    );
+    assert_eq!(check_invariant(1u8, succ, |x| *x != 1).unwrap_err(), [1]);
+}
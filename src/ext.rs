@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Provides methods for stepping between adjacent values of a [`Finite`] type, according to the
+/// order given by [`Finite::index_of`]. This is implemented for all [`Finite`] types.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Light {
+///     Red,
+///     Yellow,
+///     Green,
+/// }
+///
+/// assert_eq!(Light::Red.succ(), Some(Light::Yellow));
+/// assert_eq!(Light::Green.succ(), None);
+/// assert_eq!(Light::Green.wrapping_next(), Light::Red);
+/// assert_eq!(Light::Red.wrapping_prev(), Light::Green);
+/// ```
+pub trait FiniteExt: Finite {
+    /// Gets the value following this one, or [`None`] if this is the last value.
+    fn succ(self) -> Option<Self> {
+        Self::nth(Self::index_of(self) + 1)
+    }
+
+    /// Gets the value preceding this one, or [`None`] if this is the first value.
+    fn pred(self) -> Option<Self> {
+        Self::index_of(self).checked_sub(1).and_then(Self::nth)
+    }
+
+    /// Gets the value following this one, wrapping around to the first value if this is the last
+    /// value.
+    fn wrapping_next(self) -> Self {
+        let index = Self::index_of(self) + 1;
+        let index = if index < Self::COUNT { index } else { 0 };
+        unsafe { Self::nth_unchecked(index) }
+    }
+
+    /// Gets the value preceding this one, wrapping around to the last value if this is the first
+    /// value.
+    fn wrapping_prev(self) -> Self {
+        let index = match Self::index_of(self) {
+            0 => Self::COUNT - 1,
+            index => index - 1,
+        };
+        unsafe { Self::nth_unchecked(index) }
+    }
+}
+
+impl<T: Finite> FiniteExt for T {}
+
+#[test]
+fn test_step() {
+    assert_eq!(false.succ(), Some(true));
+    assert_eq!(true.succ(), None);
+    assert_eq!(false.pred(), None);
+    assert_eq!(true.pred(), Some(false));
+    assert_eq!(255u8.wrapping_next(), 0);
+    assert_eq!(0u8.wrapping_prev(), 255);
+    assert_eq!(i8::MAX.wrapping_next(), i8::MIN);
+    assert_eq!(().wrapping_next(), ());
+}
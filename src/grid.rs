@@ -0,0 +1,215 @@
+use crate::*;
+
+/// Identifies a set of cells surrounding a cell in a two-dimensional grid.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Neighborhood {
+    /// The 4 cells which share an edge with a cell.
+    VonNeumann,
+
+    /// The 8 cells which share an edge or a corner with a cell.
+    Moore,
+}
+
+/// Determines how a grid is extended beyond its edges when looking up neighbors.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Boundary {
+    /// Coordinates wrap around to the opposite edge of the grid, making it a torus.
+    Wrap,
+
+    /// Coordinates outside of the grid are clamped to the nearest edge. Note that this may cause
+    /// a cell to be its own neighbor.
+    Clamp,
+}
+
+impl Neighborhood {
+    /// The offsets to the neighbors of a cell, in lexicographic order.
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::VonNeumann => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+impl Boundary {
+    /// Applies an offset to an index into a dimension of the given size.
+    fn offset(&self, index: usize, offset: isize, size: usize) -> usize {
+        match self {
+            Boundary::Wrap => (index + size).wrapping_add_signed(offset) % size,
+            Boundary::Clamp => index.saturating_add_signed(offset).min(size - 1),
+        }
+    }
+}
+
+/// A [`Finite`] type whose values can be interpreted as the cells of a two-dimensional grid with
+/// coordinates of type `X` and `Y`. Coordinates are adjacent iff their indices are adjacent.
+///
+/// Implementors must have the same index layout as the tuple `(X, Y)`, i.e. the index of a cell
+/// must be `X::index_of(x) * Y::COUNT + Y::index_of(y)`. This is the case for `(X, Y)` itself and
+/// for structs with fields of types `X` and `Y` (in that order) that derive [`Finite`]. This
+/// layout isn't checked in general, so a mismatched implementation will produce the wrong
+/// neighbors, though a mismatched `COUNT` is caught by debug assertions.
+pub trait GridCell: Finite {
+    /// The type of the first coordinate of a cell.
+    type X: Finite;
+
+    /// The type of the second coordinate of a cell.
+    type Y: Finite;
+}
+
+impl<X: Finite, Y: Finite> GridCell for (X, Y) {
+    type X = X;
+    type Y = Y;
+}
+
+/// Iterates over the neighbors of a cell in a grid.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Coord {
+///     A,
+///     B,
+///     C
+/// }
+///
+/// let around = neighbors((Coord::A, Coord::B), Neighborhood::VonNeumann, Boundary::Wrap);
+/// assert_eq!(
+///     around.collect::<Vec<_>>(),
+///     [(Coord::C, Coord::B), (Coord::A, Coord::A), (Coord::A, Coord::C), (Coord::B, Coord::B)]
+/// );
+/// ```
+pub fn neighbors<K: GridCell>(
+    cell: K,
+    neighborhood: Neighborhood,
+    boundary: Boundary,
+) -> Neighbors<K> {
+    debug_assert_eq!(
+        Some(K::COUNT),
+        K::X::COUNT.checked_mul(K::Y::COUNT),
+        "`GridCell` must have the same index layout as `(X, Y)`"
+    );
+    let index = K::index_of(cell);
+    Neighbors {
+        x: index / K::Y::COUNT,
+        y: index % K::Y::COUNT,
+        offsets: neighborhood.offsets().iter(),
+        boundary,
+        marker: PhantomData,
+    }
+}
+
+/// An iterator over the neighbors of a cell in a grid. See [`neighbors`].
+pub struct Neighbors<K: GridCell> {
+    x: usize,
+    y: usize,
+    offsets: core::slice::Iter<'static, (isize, isize)>,
+    boundary: Boundary,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: GridCell> Iterator for Neighbors<K> {
+    type Item = K;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (dx, dy) = self.offsets.next()?;
+        let x = self.boundary.offset(self.x, *dx, K::X::COUNT);
+        let y = self.boundary.offset(self.y, *dy, K::Y::COUNT);
+        Some(K::nth(x * K::Y::COUNT + y).unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<K: GridCell> ExactSizeIterator for Neighbors<K> {}
+
+/// Computes the next generation of a grid by applying a local rule to every cell. The rule is
+/// given a cell, its current value, and an iterator over the current values of its neighbors.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Coord {
+///     A,
+///     B,
+///     C,
+///     D
+/// }
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Cell(Coord, Coord);
+///
+/// impl GridCell for Cell {
+///     type X = Coord;
+///     type Y = Coord;
+/// }
+///
+/// // Conway's game of life: a blinker oscillates with period 2
+/// let life = |_, alive: &bool, around: Stencil<Cell, bool>| {
+///     let count = around.filter(|n| **n).count();
+///     count == 3 || (*alive && count == 2)
+/// };
+/// let start = ArrayMap::new(|Cell(x, y)| y == Coord::B && x != Coord::D);
+/// let next = step(&start, Neighborhood::Moore, Boundary::Wrap, life);
+/// assert!(next[Cell(Coord::B, Coord::A)] && next[Cell(Coord::B, Coord::C)]);
+/// assert!(!next[Cell(Coord::A, Coord::B)]);
+/// assert!(step(&next, Neighborhood::Moore, Boundary::Wrap, life) == start);
+/// ```
+pub fn step<K: GridCell + ArrayFinite<V>, V>(
+    map: &ArrayMap<K, V>,
+    neighborhood: Neighborhood,
+    boundary: Boundary,
+    mut rule: impl FnMut(K, &V, Stencil<K, V>) -> V,
+) -> ArrayMap<K, V> {
+    map.map_with_key(|cell, value| {
+        let around = Stencil {
+            map,
+            neighbors: neighbors(cell.clone(), neighborhood, boundary),
+        };
+        rule(cell, value, around)
+    })
+}
+
+/// An iterator over the values of the neighbors of a cell in a grid. See [`step`].
+pub struct Stencil<'a, K: GridCell + ArrayFinite<V>, V> {
+    map: &'a ArrayMap<K, V>,
+    neighbors: Neighbors<K>,
+}
+
+impl<'a, K: GridCell + ArrayFinite<V>, V> Iterator for Stencil<'a, K, V> {
+    type Item = &'a V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.neighbors.next().map(|cell| &self.map[cell])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.neighbors.size_hint()
+    }
+}
+
+impl<'a, K: GridCell + ArrayFinite<V>, V> ExactSizeIterator for Stencil<'a, K, V> {}
+
+#[test]
+fn test_neighbors_clamp() {
+    let around = neighbors((false, true), Neighborhood::Moore, Boundary::Clamp);
+    assert_eq!(around.len(), 8);
+    let mut count = [0; 4];
+    for (x, y) in around {
+        count[(x as usize) * 2 + (y as usize)] += 1;
+    }
+    assert_eq!(count, [2, 3, 1, 2]);
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
 #![warn(missing_docs)]
 //! This crate provides the [`Finite`] trait for use with types with a small number of values
 //! (typically, but not exclusively `enum`s). Deriving this trait enables several useful
@@ -6,19 +7,95 @@
 //! * [Value iteration](Finite::iter)
 //! * [Value compression](Compress)
 //! * [Array-based maps](ArrayMap)
+//! * [Partial array-based maps](PartialArrayMap)
+//! * [Frequency counting](Counter)
+//! * [Vec-based maps](VecMap) (requires the `alloc` feature)
+//! * [Memoization](MemoMap) (requires the `alloc` feature)
 //! * [Bitmap sets](BitmapSet)
+//! * [Heap-allocated bitmap sets](DynBitmapSet) with [rank/select indices](IndexedBitmapSet)
+//!   (requires the `alloc` feature)
+//! * [Array sets](ArraySet)
+//! * [Sparse sets](VecSet) (requires the `alloc` feature)
+//! * [Semiring matrices and relations](Matrix)
+//! * [Binary decision diagrams](bdd) (requires the `alloc` feature)
+//! * [Truth table minimization](TruthTable) (requires the `alloc` feature)
 extern crate self as cantor;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 pub mod uint;
 pub mod array;
+mod array_set;
+mod bijection;
+#[cfg(feature = "alloc")]
+pub mod bdd;
 mod compress;
+mod counter;
+mod cursor;
+#[cfg(feature = "alloc")]
+mod dyn_bitmap_set;
+mod error;
+#[cfg(feature = "alloc")]
+mod explore;
+mod ext;
+mod grid;
 mod map;
+mod matrix;
+#[cfg(feature = "alloc")]
+mod memo;
+#[cfg(feature = "rayon")]
+mod par;
+mod partial_map;
+mod product;
+#[cfg(feature = "rand")]
+mod random;
+mod search;
 mod set;
+mod shuffle;
+#[cfg(feature = "nightly")]
+mod step;
+#[cfg(feature = "alloc")]
+mod truth_table;
+#[cfg(feature = "alloc")]
+mod vec_map;
+#[cfg(feature = "alloc")]
+mod vec_set;
 
+pub use array_set::*;
+pub use bijection::*;
 pub use cantor_macros::*;
 pub use compress::*;
+pub use counter::*;
+pub use cursor::*;
+#[cfg(feature = "alloc")]
+pub use dyn_bitmap_set::*;
+pub use error::*;
+#[cfg(feature = "alloc")]
+pub use explore::*;
+pub use ext::*;
+pub use grid::*;
 pub use map::*;
+pub use matrix::*;
+#[cfg(feature = "alloc")]
+pub use memo::*;
+#[cfg(feature = "rayon")]
+pub use par::*;
+pub use partial_map::*;
+pub use product::*;
+pub use search::*;
 pub use set::*;
+pub use shuffle::*;
+#[cfg(feature = "nightly")]
+pub use step::*;
+#[cfg(feature = "alloc")]
+pub use truth_table::*;
+#[cfg(feature = "alloc")]
+pub use vec_map::*;
+#[cfg(feature = "alloc")]
+pub use vec_set::*;
 use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
 
 /// Provides the number of values for a type, as well as a 1-to-1 mapping between the subset of
 /// integers [0 .. N) and those values. The ordering of integers in this mapping is homomorphic to
@@ -43,9 +120,78 @@ use core::marker::PhantomData;
 /// assert_eq!(MyType::nth(4), Some(MyType::C(false, true)));
 /// ```
 /// 
+/// The derived `COUNT` is checked for overflow, so it is a compile-time error for a type to have
+/// more than `usize::MAX` values, whether through its fields:
+/// ```compile_fail
+/// use cantor::*;
+/// 
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Wide(u16, u16, u16, u16, u16);
+/// ```
+/// 
+/// or through its variants:
+/// ```compile_fail
+/// use cantor::*;
+/// 
+/// finite_range!(struct Huge(0..=18446744073709551614): u64);
+/// 
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Overflow {
+///     A(bool),
+///     B(Huge),
+/// }
+/// ```
+/// 
+/// # Attributes
+/// The derived implementation can be configured using `#[finite(...)]` attributes on the type:
+/// * `binary_search`: for enums, implement `nth` using a binary search over the variants rather
+///   than a chain of comparisons.
+/// * `table`: for fieldless enums, implement `nth` by indexing into a table of all values.
+/// * `const_fn`: for fieldless enums, also implement inherent `index_of_const` and `nth_const`
+///   methods which can be used in `const` contexts.
+/// * `convert`: also implement `TryFrom<usize>` for the type and `From<T>` for `usize`.
+/// * `names`: for fieldless enums, also implement a `NAMES` table, a `name` method and `FromStr`.
+/// * `self_test`: generate a unit test which checks the implementation using [`validate`].
+/// * `concrete = "..."`: for generic types, an instantiation to implement helper traits (e.g.
+///   [`ArrayFinite`]) for. This may be repeated.
+/// * `index_type = "..."`: the index type to use for [`Compress`], overriding the smallest type
+///   that fits.
+/// 
+/// And on enum variants:
+/// * `skip`: exclude the variant from the enumeration. `index_of` will panic if given it.
+/// * `index = N`: require the variant to start at index `N`, so that reordering or changing
+///   variants can't silently change indices.
+/// 
+/// ```
+/// use cantor::*;
+/// 
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// #[finite(convert, names, index_type = "u16")]
+/// enum Op {
+///     #[finite(index = 0)]
+///     Nop,
+///     #[finite(skip)]
+///     Debug,
+///     #[finite(index = 1)]
+///     Halt,
+/// }
+/// 
+/// assert_eq!(Op::COUNT, 2);
+/// assert_eq!(usize::from(Op::Halt), 1);
+/// assert_eq!(Op::try_from(0).ok(), Some(Op::Nop));
+/// assert_eq!("Halt".parse::<Op>().ok(), Some(Op::Halt));
+/// assert_eq!(Op::Nop.name(), "Nop");
+/// assert_eq!(core::mem::size_of::<Compress<Op>>(), 2);
+/// ```
+/// 
 /// # Safety
 /// `index_of` must return an integer less than `COUNT`. `nth` must return a non-`None` value iff
 /// it is given an integer less than `COUNT`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Finite`",
+    label = "`{Self}` does not implement `Finite`",
+    note = "`Finite` can be derived for structs and enums whose fields all implement `Finite`"
+)]
 pub unsafe trait Finite: Ord + Clone + Sized {
     /// The number of valid values of this type.
     const COUNT: usize;
@@ -58,42 +204,162 @@ pub unsafe trait Finite: Ord + Clone + Sized {
     /// [`None`] if the index is out of bounds.
     fn nth(index: usize) -> Option<Self>;
 
+    /// Gets the value with the given index as returned by [`Finite::index_of`], or returns a
+    /// [`TryFromIndexError`] if the index is out of bounds.
+    fn try_nth(index: usize) -> Result<Self, TryFromIndexError> {
+        Self::nth(index).ok_or(TryFromIndexError::new(index, Self::COUNT))
+    }
+
+    /// Gets the value with the given index as returned by [`Finite::index_of`], without checking
+    /// that the index is in bounds.
+    ///
+    /// # Safety
+    /// `index` must be less than [`Finite::COUNT`].
+    #[inline]
+    unsafe fn nth_unchecked(index: usize) -> Self {
+        Self::nth(index).unwrap_unchecked()
+    }
+
+    /// Gets the first value of this type (i.e. the one with index 0), or [`None`] if this type has
+    /// no values.
+    fn first() -> Option<Self> {
+        Self::nth(0)
+    }
+
+    /// Gets the last value of this type (i.e. the one with index `COUNT - 1`), or [`None`] if this
+    /// type has no values.
+    fn last() -> Option<Self> {
+        Self::nth(Self::COUNT.checked_sub(1)?)
+    }
+
     /// Iterates over all of the values of this type.
     fn iter() -> FiniteIter<Self> {
         FiniteIter {
             index: 0,
+            end: Self::COUNT,
             marker: PhantomData
         }
     }
+
+    /// Iterates over all of the values of this type within the given range.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// assert!(u8::range(3..=5).eq([3, 4, 5]));
+    /// assert!(<(bool, bool)>::range((true, false)..).eq([(true, false), (true, true)]));
+    /// ```
+    fn range(range: impl RangeBounds<Self>) -> FiniteIter<Self> {
+        let end = match range.end_bound() {
+            Bound::Included(value) => Self::index_of(value.clone()) + 1,
+            Bound::Excluded(value) => Self::index_of(value.clone()),
+            Bound::Unbounded => Self::COUNT
+        };
+        let index = match range.start_bound() {
+            Bound::Included(value) => Self::index_of(value.clone()),
+            Bound::Excluded(value) => Self::index_of(value.clone()) + 1,
+            Bound::Unbounded => 0
+        };
+        FiniteIter {
+            index: index.min(end),
+            end,
+            marker: PhantomData
+        }
+    }
+}
+
+/// Iterates over all of the values of a [`Finite`] type. This is equivalent to [`Finite::iter`],
+/// but can be more convenient in generic code.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// fn count_true<T: Finite>(f: impl Fn(T) -> bool) -> usize {
+///     values::<T>().filter(|value| f(value.clone())).count()
+/// }
+/// assert_eq!(count_true(|(a, b): (bool, bool)| a || b), 3);
+/// ```
+pub fn values<T: Finite>() -> FiniteIter<T> {
+    T::iter()
 }
 
 /// An iterator over all of the values of a [`Finite`] type.
 pub struct FiniteIter<T: Finite> {
     index: usize,
+    end: usize,
     marker: PhantomData<fn() -> T>
 }
 
+impl<T: Finite> Clone for FiniteIter<T> {
+    fn clone(&self) -> Self {
+        FiniteIter {
+            index: self.index,
+            end: self.end,
+            marker: PhantomData
+        }
+    }
+}
+
 impl<T: Finite> Iterator for FiniteIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        let res = T::nth(self.index);
-        self.index += 1;
-        res
+        if self.index < self.end {
+            let res = unsafe { T::nth_unchecked(self.index) };
+            self.index += 1;
+            Some(res)
+        } else {
+            None
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let rem = T::COUNT - self.index;
+        let rem = self.end - self.index;
         (rem, Some(rem))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.end);
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T: Finite> DoubleEndedIterator for FiniteIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(unsafe { T::nth_unchecked(self.end) })
+        } else {
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end = self.end.saturating_sub(n).max(self.index);
+        self.next_back()
+    }
 }
 
+impl<T: Finite> ExactSizeIterator for FiniteIter<T> {}
+
+impl<T: Finite> core::iter::FusedIterator for FiniteIter<T> {}
+
 unsafe impl Finite for () {
     const COUNT: usize = 1;
 
+    #[inline]
     fn index_of(_: Self) -> usize {
         0
     }
 
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         if index == 0 {
             Some(())
@@ -106,10 +372,12 @@ unsafe impl Finite for () {
 unsafe impl Finite for bool {
     const COUNT: usize = 2;
 
+    #[inline]
     fn index_of(value: Self) -> usize {
         value as usize
     }
 
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         match index {
             0 => Some(false),
@@ -122,10 +390,12 @@ unsafe impl Finite for bool {
 unsafe impl Finite for u8 {
     const COUNT: usize = 1 << 8;
 
+    #[inline]
     fn index_of(value: Self) -> usize {
         value as usize
     }
 
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         if index < Self::COUNT {
             Some(index as u8)
@@ -138,10 +408,12 @@ unsafe impl Finite for u8 {
 unsafe impl Finite for u16 {
     const COUNT: usize = 1 << 16;
 
+    #[inline]
     fn index_of(value: Self) -> usize {
         value as usize
     }
 
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         if index < Self::COUNT {
             Some(index as u16)
@@ -151,9 +423,101 @@ unsafe impl Finite for u16 {
     }
 }
 
+#[cfg(all(feature = "large-primitives", not(target_pointer_width = "64")))]
+compile_error!("the `large-primitives` feature requires a 64-bit target");
+
+#[cfg(all(feature = "large-primitives", target_pointer_width = "64"))]
+unsafe impl Finite for u32 {
+    const COUNT: usize = 1 << 32;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        value as usize
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some(index as u32)
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl Finite for i8 {
+    const COUNT: usize = 1 << 8;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        value.wrapping_sub(i8::MIN) as u8 as usize
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some((index as u8 as i8).wrapping_add(i8::MIN))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl Finite for i16 {
+    const COUNT: usize = 1 << 16;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        value.wrapping_sub(i16::MIN) as u16 as usize
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some((index as u16 as i16).wrapping_add(i16::MIN))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl Finite for core::convert::Infallible {
+    const COUNT: usize = 0;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        match value {}
+    }
+
+    #[inline]
+    fn nth(_: usize) -> Option<Self> {
+        None
+    }
+}
+
+unsafe impl Finite for core::cmp::Ordering {
+    const COUNT: usize = 3;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        (value as i8 + 1) as usize
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(core::cmp::Ordering::Less),
+            1 => Some(core::cmp::Ordering::Equal),
+            2 => Some(core::cmp::Ordering::Greater),
+            _ => None,
+        }
+    }
+}
+
 unsafe impl<T: Finite> Finite for Option<T> {
     const COUNT: usize = 1 + T::COUNT;
 
+    #[inline]
     fn index_of(value: Self) -> usize {
         match value {
             Some(value) => 1 + T::index_of(value),
@@ -161,54 +525,248 @@ unsafe impl<T: Finite> Finite for Option<T> {
         }
     }
 
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         if index == 0 {
             Some(None)
         } else if index < Self::COUNT {
-            Some(T::nth(index - 1))
+            Some(Some(unsafe { T::nth_unchecked(index - 1) }))
         } else {
             None
         }
     }
 }
 
-unsafe impl<A: Finite, B: Finite> Finite for (A, B) {
-    const COUNT: usize = A::COUNT * B::COUNT;
+unsafe impl<A: Finite, B: Finite> Finite for Result<A, B> {
+    const COUNT: usize = A::COUNT + B::COUNT;
 
+    #[inline]
     fn index_of(value: Self) -> usize {
-        A::index_of(value.0) * B::COUNT + B::index_of(value.1)
+        match value {
+            Ok(value) => A::index_of(value),
+            Err(value) => A::COUNT + B::index_of(value),
+        }
     }
 
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < A::COUNT {
+            Some(Ok(unsafe { A::nth_unchecked(index) }))
+        } else if index < Self::COUNT {
+            Some(Err(unsafe { B::nth_unchecked(index - A::COUNT) }))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<T: Finite> Finite for core::task::Poll<T> {
+    const COUNT: usize = T::COUNT + 1;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        match value {
+            core::task::Poll::Ready(value) => T::index_of(value),
+            core::task::Poll::Pending => T::COUNT,
+        }
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < T::COUNT {
+            Some(core::task::Poll::Ready(unsafe { T::nth_unchecked(index) }))
+        } else if index == T::COUNT {
+            Some(core::task::Poll::Pending)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "either")]
+unsafe impl<A: Finite, B: Finite> Finite for either::Either<A, B> {
+    const COUNT: usize = A::COUNT + B::COUNT;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        match value {
+            either::Either::Left(value) => A::index_of(value),
+            either::Either::Right(value) => A::COUNT + B::index_of(value),
+        }
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index < A::COUNT {
+            Some(either::Either::Left(unsafe { A::nth_unchecked(index) }))
+        } else if index < Self::COUNT {
+            Some(either::Either::Right(unsafe {
+                B::nth_unchecked(index - A::COUNT)
+            }))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<T: ?Sized> Finite for PhantomData<T> {
+    const COUNT: usize = 1;
+
+    #[inline]
+    fn index_of(_: Self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        if index == 0 {
+            Some(PhantomData)
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<T: Finite> Finite for core::cmp::Reverse<T> {
+    const COUNT: usize = T::COUNT;
+
+    #[inline]
+    fn index_of(value: Self) -> usize {
+        T::COUNT - 1 - T::index_of(value.0)
+    }
+
+    #[inline]
     fn nth(index: usize) -> Option<Self> {
         if index < Self::COUNT {
-            Some((
-                A::nth(index / B::COUNT).unwrap(),
-                B::nth(index % B::COUNT).unwrap(),
-            ))
+            Some(core::cmp::Reverse(unsafe {
+                T::nth_unchecked(T::COUNT - 1 - index)
+            }))
         } else {
             None
         }
     }
 }
 
+macro_rules! impl_tuple_finite {
+    ($($t:ident $v:ident),+) => {
+        unsafe impl<$($t: Finite),+> Finite for ($($t,)+) {
+            const COUNT: usize = 1 $(* $t::COUNT)+;
+
+            #[inline]
+            fn index_of(value: Self) -> usize {
+                let ($($v,)+) = value;
+                let index = 0;
+                $(let index = index * $t::COUNT + $t::index_of($v);)+
+                index
+            }
+
+            #[inline]
+            fn nth(index: usize) -> Option<Self> {
+                if index < Self::COUNT {
+                    Some(unsafe { Self::nth_unchecked(index) })
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            unsafe fn nth_unchecked(index: usize) -> Self {
+                // `stride` is the product of the counts of the components after the current one
+                let stride = Self::COUNT;
+                $(
+                    let stride = stride / $t::COUNT;
+                    let $v = $t::nth_unchecked((index / stride) % $t::COUNT);
+                )+
+                ($($v,)+)
+            }
+        }
+    };
+}
+
+impl_tuple_finite!(A a, B b);
+impl_tuple_finite!(A a, B b, C c);
+impl_tuple_finite!(A a, B b, C c, D d);
+impl_tuple_finite!(A a, B b, C c, D d, E e);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g, H h);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
+impl_tuple_finite!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
+
+/// Checks that the [`Finite`] implementation for `T` is valid, i.e. that [`Finite::nth`] and
+/// [`Finite::index_of`] are inverse bijections between the values of `T` and `0..COUNT`, and that
+/// [`Finite::nth`] preserves order. This is useful for testing manual implementations, and is
+/// also used by the tests generated by `#[finite(self_test)]`.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert_eq!(validate::<(bool, u8)>(), Ok(()));
+/// ```
+pub fn validate<T: Finite>() -> Result<(), ValidationError> {
+    let mut prev = None;
+    for index in 0..T::COUNT {
+        let value = T::nth(index).ok_or(ValidationError::MissingValue { index })?;
+        let actual = T::index_of(value.clone());
+        if actual != index {
+            return Err(ValidationError::WrongIndex { index, actual });
+        }
+        if let Some(prev) = prev {
+            if prev >= value {
+                return Err(ValidationError::OutOfOrder { index });
+            }
+        }
+        prev = Some(value);
+    }
+    if T::nth(T::COUNT).is_some() {
+        return Err(ValidationError::ExtraValue { index: T::COUNT });
+    }
+    Ok(())
+}
+
 /// Implements helper traits for a concrete (i.e. non-parameteric) type that implements `Finite`.
+///
+/// This may also be used for a particular instantiation of a generic type, e.g.
+/// `impl_concrete_finite!(MyWrapper<bool>)`. When deriving [`Finite`] on a generic type, the
+/// instantiations can instead be listed using `#[finite(concrete = "MyWrapper<bool>")]`.
+///
+/// By default, [`Compress`] uses the smallest possible index type. A different index type can be
+/// specified using `impl_concrete_finite!(MyType, index_type = u16)`, or
+/// `#[finite(index_type = "u16")]` when deriving [`Finite`]. It is a compile-time error for the
+/// index type to be too small.
 #[macro_export]
 macro_rules! impl_concrete_finite {
     ($t:ty) => {
+        $crate::impl_concrete_finite!($t, index_type = ::cantor::uint::Uint<{
+            ::cantor::uint::log2(<$t as ::cantor::Finite>::COUNT.saturating_sub(1))
+        }>);
+    };
+    ($t:ty, index_type = $index_type:ty) => {
+        const _: () = assert!(
+            ::cantor::uint::log2(<$t as ::cantor::Finite>::COUNT.saturating_sub(1))
+                <= 8 * ::core::mem::size_of::<$index_type>(),
+            "index type is too small"
+        );
         unsafe impl ::cantor::CompressFinite for $t {
-            type Index = ::cantor::uint::Uint<{ 
-                ::cantor::uint::log2(<$t as ::cantor::Finite>::COUNT - 1)
-            }>;
+            type Index = $index_type;
         }
         unsafe impl<V> ::cantor::ArrayFinite<V> for $t {
             type Array = [V; <$t as ::cantor::Finite>::COUNT];
         }
         unsafe impl ::cantor::BitmapFinite for $t
         where
-            for<'a> ::cantor::uint::NumBits<'a, { <$t as ::cantor::Finite>::COUNT }>:
-                ::cantor::uint::HasUint
+            for<'a> ::cantor::uint::NumBitmapBits<
+                'a,
+                { ::cantor::uint::bitmap_bits(<$t as ::cantor::Finite>::COUNT) },
+                { ::cantor::uint::bitmap_words(<$t as ::cantor::Finite>::COUNT) },
+            >: ::cantor::uint::HasBitmapUint,
         {
-            type Bitmap = ::cantor::uint::Uint<{ <$t as ::cantor::Finite>::COUNT }>;
+            type Bitmap = ::cantor::uint::BitmapUint<
+                { ::cantor::uint::bitmap_bits(<$t as ::cantor::Finite>::COUNT) },
+                { ::cantor::uint::bitmap_words(<$t as ::cantor::Finite>::COUNT) },
+            >;
         }
     };
 }
@@ -217,6 +775,229 @@ impl_concrete_finite!(());
 impl_concrete_finite!(bool);
 impl_concrete_finite!(u8);
 impl_concrete_finite!(u16);
+#[cfg(all(feature = "large-primitives", target_pointer_width = "64"))]
+impl_concrete_finite!(u32);
+impl_concrete_finite!(i8);
+impl_concrete_finite!(i16);
+impl_concrete_finite!(core::cmp::Ordering);
+impl_concrete_finite!(core::convert::Infallible);
+
+/// Implements [`Finite`] and its helper traits for a type by explicitly listing all of its values.
+/// The values must be listed in strictly ascending order. In debug builds, this is checked once,
+/// the first time the implementation is used.
+///
+/// This is useful for types which can't use the derive macro, e.g. because they are generated by
+/// another macro. Note that, as usual, the implementing crate must own either the type or the
+/// [`Finite`] trait.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+/// impl_finite!(Suit = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]);
+/// assert_eq!(Suit::COUNT, 4);
+/// assert_eq!(Suit::index_of(Suit::Hearts), 2);
+/// assert_eq!(Suit::nth(3), Some(Suit::Spades));
+/// ```
+#[macro_export]
+macro_rules! impl_finite {
+    ($t:ty = [$($value:expr),* $(,)?]) => {
+        const _: () = {
+            const VALUES: [$t; <$t as $crate::Finite>::COUNT] = [$($value),*];
+
+            /// Checks that `VALUES` is sorted. In debug builds, this is done the first time the
+            /// implementation is used, rather than on every call.
+            #[inline]
+            fn validate() {
+                #[cfg(debug_assertions)]
+                {
+                    use core::sync::atomic::{AtomicBool, Ordering};
+                    static VALIDATED: AtomicBool = AtomicBool::new(false);
+                    if !VALIDATED.load(Ordering::Relaxed) {
+                        assert!(
+                            VALUES.windows(2).all(|w| w[0] < w[1]),
+                            "values must be listed in strictly ascending order"
+                        );
+                        VALIDATED.store(true, Ordering::Relaxed);
+                    }
+                }
+            }
+
+            unsafe impl $crate::Finite for $t {
+                const COUNT: usize = [$(stringify!($value)),*].len();
+
+                fn index_of(value: Self) -> usize {
+                    validate();
+                    VALUES
+                        .binary_search(&value)
+                        .expect("value is not listed for this type")
+                }
+
+                fn nth(index: usize) -> Option<Self> {
+                    validate();
+                    VALUES.get(index).cloned()
+                }
+            }
+        };
+
+        $crate::impl_concrete_finite!($t);
+    };
+}
+
+/// Defines a newtype over an inclusive range of integers, implementing [`Finite`] and its helper
+/// traits for it.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// finite_range!(pub struct Dice(1..=6): u8);
+/// assert_eq!(Dice::COUNT, 6);
+/// assert_eq!(Dice::new(7), None);
+/// let roll = Dice::new(4).unwrap();
+/// assert_eq!(roll.get(), 4);
+/// assert_eq!(Dice::index_of(roll), 3);
+/// assert_eq!(Dice::nth(0), Dice::new(1));
+/// ```
+///
+/// The range must not be empty, and must have at most `usize::MAX` values. These are checked at
+/// compile time:
+/// ```compile_fail
+/// use cantor::*;
+/// finite_range!(struct Backwards(6..=1): u8);
+/// ```
+/// ```compile_fail
+/// use cantor::*;
+/// finite_range!(struct Full(0..=18446744073709551615): u64);
+/// ```
+#[macro_export]
+macro_rules! finite_range {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident($min:literal..=$max:literal): $t:ty) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+        $vis struct $name($t);
+
+        const _: () = assert!(
+            $name::MIN.0 <= $name::MAX.0,
+            "the minimum of a `finite_range!` type must not exceed its maximum"
+        );
+
+        #[allow(dead_code)]
+        impl $name {
+            /// The smallest value of this type.
+            pub const MIN: Self = Self($min);
+
+            /// The largest value of this type.
+            pub const MAX: Self = Self($max);
+
+            /// Constructs a value of this type, returning `None` if `value` is out of range.
+            pub const fn new(value: $t) -> Option<Self> {
+                if Self::MIN.0 <= value && value <= Self::MAX.0 {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Constructs a value of this type without checking that `value` is in range.
+            ///
+            /// # Safety
+            /// `value` must be within the range of this type.
+            pub const unsafe fn new_unchecked(value: $t) -> Self {
+                Self(value)
+            }
+
+            /// Gets the integer value for this value.
+            pub const fn get(self) -> $t {
+                self.0
+            }
+        }
+
+        #[allow(clippy::unnecessary_cast)]
+        unsafe impl $crate::Finite for $name {
+            const COUNT: usize = {
+                // `abs_diff` gives an unsigned integer of the same width, so this can't overflow
+                let span = $name::MAX.0.abs_diff($name::MIN.0) as u128;
+                assert!(
+                    span < usize::MAX as u128,
+                    "a `finite_range!` type must have at most `usize::MAX` values"
+                );
+                span as usize + 1
+            };
+
+            #[inline]
+            fn index_of(value: Self) -> usize {
+                value.0.abs_diff($name::MIN.0) as usize
+            }
+
+            #[inline]
+            fn nth(index: usize) -> Option<Self> {
+                if index < <Self as $crate::Finite>::COUNT {
+                    // `index` fits in the width of the integer type, so wrapping arithmetic gives
+                    // the correct result for both signed and unsigned types
+                    Some(Self($name::MIN.0.wrapping_add(index as $t)))
+                } else {
+                    None
+                }
+            }
+        }
+
+        $crate::impl_concrete_finite!($name);
+    };
+}
+
+/// Constructs a [`BitmapSet`] containing the given values.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum MyType {
+///     A,
+///     B(bool),
+///     C(bool, bool)
+/// }
+///
+/// let set = bitmap_set![MyType::A, MyType::B(false)];
+/// assert_eq!(set.size(), 2);
+/// assert!(set.contains(MyType::B(false)));
+/// ```
+#[macro_export]
+macro_rules! bitmap_set {
+    ($($value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut set = $crate::BitmapSet::none();
+        $($crate::Set::include(&mut set, $value);)*
+        set
+    }};
+}
+
+/// Constructs an [`ArrayMap`] from a list of key-value pairs, followed by a default value for all
+/// keys which aren't listed. The default value expression is evaluated once for each key.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// use core::cmp::Ordering;
+/// let map = array_map![Ordering::Less => "less", _ => "not less"];
+/// assert_eq!(map[Ordering::Less], "less");
+/// assert_eq!(map[Ordering::Greater], "not less");
+/// ```
+#[macro_export]
+macro_rules! array_map {
+    ($($key:expr => $value:expr,)* _ => $default:expr $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = $crate::ArrayMap::new(|_| $default);
+        $(map[$key] = $value;)*
+        map
+    }};
+}
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
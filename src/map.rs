@@ -30,6 +30,7 @@ use core::ops::{Index, IndexMut};
 /// assert_eq!(map[MyType::B(true)], true);
 /// assert_eq!(map[MyType::C(true, true)], false);
 /// ```
+#[repr(transparent)]
 pub struct ArrayMap<K: ArrayFinite<V>, V>(K::Array);
 
 /// The trait required to use [`ArrayMap`]. Theoretically, this should apply to all
@@ -48,9 +49,23 @@ pub unsafe trait ArrayFinite<V>: Finite {
 impl<K: ArrayFinite<V>, V> ArrayMap<K, V> {
     /// Constructs a new [`ArrayMap`] with initial values populated using the given function.
     pub fn new(mut f: impl FnMut(K) -> V) -> Self {
-        ArrayMap(K::Array::new(|k| {
-            f(unsafe { K::nth(k).unwrap_unchecked() })
-        }))
+        ArrayMap(K::Array::new(|k| f(unsafe { K::nth_unchecked(k) })))
+    }
+
+    /// Constructs a new [`ArrayMap`] with initial values populated using the given fallible
+    /// function. If the function returns an error for any key, the error is returned and the
+    /// values created so far are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::try_new(|x: bool| if x { Ok(1) } else { Err("false") });
+    /// assert!(matches!(map, Err("false")));
+    /// ```
+    pub fn try_new<E>(mut f: impl FnMut(K) -> Result<V, E>) -> Result<Self, E> {
+        Ok(ArrayMap(K::Array::try_new(|k| {
+            f(unsafe { K::nth_unchecked(k) })
+        })?))
     }
 
     /// Constructs a new [`ArrayMap`] from an array of values, each corresponding to the key
@@ -67,16 +82,90 @@ impl<K: ArrayFinite<V>, V> ArrayMap<K, V> {
         Self(array)
     }
 
+    /// Views an array of values, each corresponding to the key determined by [`Finite::nth`], as
+    /// an [`ArrayMap`].
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let array = [1, 3];
+    /// let map: &ArrayMap<bool, i32> = ArrayMap::from_ref(&array);
+    /// assert_eq!(map[true], 3);
+    /// ```
+    pub fn from_ref(array: &K::Array) -> &Self {
+        // SAFETY: `ArrayMap` is a transparent wrapper around `K::Array`
+        unsafe { &*(array as *const K::Array as *const Self) }
+    }
+
+    /// Mutably views an array of values, each corresponding to the key determined by
+    /// [`Finite::nth`], as an [`ArrayMap`].
+    pub fn from_mut(array: &mut K::Array) -> &mut Self {
+        // SAFETY: `ArrayMap` is a transparent wrapper around `K::Array`
+        unsafe { &mut *(array as *mut K::Array as *mut Self) }
+    }
+
+    /// Gets the values of this map as a slice, in order of key index.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: bool| x as u8 + 1);
+    /// assert_eq!(map.as_slice(), &[1, 2]);
+    /// ```
+    pub fn as_slice(&self) -> &[V] {
+        self.0.as_slice()
+    }
+
+    /// Gets the values of this map as a mutable slice, in order of key index.
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        self.0.as_slice_mut()
+    }
+
+    /// Gets the underlying array of values for this map, in order of key index. This is the
+    /// inverse of [`ArrayMap::from`].
+    pub fn into_inner(self) -> K::Array {
+        self.0
+    }
+
+    /// Constructs a new [`ArrayMap`] from an iterator of key-value pairs, which must specify
+    /// exactly one value for each key.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::try_from_iter([(true, 1), (false, 2)]).unwrap();
+    /// assert_eq!(map[false], 2);
+    /// let err = ArrayMap::<bool, i32>::try_from_iter([(true, 1)]).err();
+    /// assert_eq!(err, Some(MapFromIterError::MissingKey(false)));
+    /// ```
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, MapFromIterError<K>>
+    where
+        K: ArrayFinite<Option<V>>,
+    {
+        let mut entries = ArrayMap::<K, Option<V>>::new(|_| None);
+        for (key, value) in iter {
+            let entry = &mut entries[key.clone()];
+            if entry.is_some() {
+                return Err(MapFromIterError::DuplicateKey(key));
+            }
+            *entry = Some(value);
+        }
+        Self::try_new(|key: K| {
+            entries[key.clone()]
+                .take()
+                .ok_or(MapFromIterError::MissingKey(key))
+        })
+    }
+
     /// Applies a mapping function the values of this map.
     pub fn map_with_key<N>(&self, mut f: impl FnMut(K, &V) -> N) -> ArrayMap<K, N>
     where
         K: ArrayFinite<N>,
     {
         ArrayMap(<K as ArrayFinite<N>>::Array::new(|k| unsafe {
-            f(
-                K::nth(k).unwrap_unchecked(),
-                self.0.as_slice().get_unchecked(k),
-            )
+            f(K::nth_unchecked(k), self.0.as_slice().get_unchecked(k))
         }))
     }
 
@@ -87,14 +176,336 @@ impl<K: ArrayFinite<V>, V> ArrayMap<K, V> {
     {
         self.map_with_key(|_, v| f(v))
     }
+
+    /// Sets every value in this map to a clone of `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: u8| x);
+    /// map.fill(3);
+    /// assert!(map.values().all(|v| *v == 3));
+    /// ```
+    pub fn fill(&mut self, value: V)
+    where
+        V: Clone,
+    {
+        self.0.as_slice_mut().fill(value)
+    }
+
+    /// Sets every value in this map to a value returned by calling `f` repeatedly.
+    pub fn fill_with(&mut self, f: impl FnMut() -> V) {
+        self.0.as_slice_mut().fill_with(f)
+    }
+
+    /// Exchanges the values for two keys.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: bool| x as u8);
+    /// map.swap(false, true);
+    /// assert_eq!(map, ArrayMap::from([1, 0]));
+    /// ```
+    pub fn swap(&mut self, a: K, b: K) {
+        self.0.as_slice_mut().swap(K::index_of(a), K::index_of(b))
+    }
+
+    /// Replaces the value for the given key, returning the previous value.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: bool| x as u8);
+    /// assert_eq!(map.replace(true, 5), 1);
+    /// assert_eq!(map[true], 5);
+    /// ```
+    pub fn replace(&mut self, key: K, value: V) -> V {
+        core::mem::replace(&mut self[key], value)
+    }
+
+    /// Computes a new value for the given key from its current value, returning the previous
+    /// value.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: bool| vec![x]);
+    /// let old = map.update(false, |v| v.iter().map(|b| !b).collect());
+    /// assert_eq!(old, [false]);
+    /// assert_eq!(map[false], [true]);
+    /// ```
+    pub fn update(&mut self, key: K, f: impl FnOnce(&V) -> V) -> V {
+        let entry = &mut self[key];
+        let value = f(entry);
+        core::mem::replace(entry, value)
+    }
+
+    /// Updates each value of this map in place using the given function. Unlike
+    /// [`ArrayMap::map_with_key`], this does not construct a new map.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: u8| x as u32);
+    /// map.transform(|k, v| *v += k as u32);
+    /// assert_eq!(map[100], 200);
+    /// ```
+    pub fn transform(&mut self, mut f: impl FnMut(K, &mut V)) {
+        for (k, v) in self.iter_mut() {
+            f(k, v)
+        }
+    }
+
+    /// Folds over the keys and values of this map, in order of key index.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: bool| if x { 5 } else { 2 });
+    /// assert_eq!(map.fold(0, |acc, k, v| if k { acc + v } else { acc - v }), 3);
+    /// ```
+    pub fn fold<A>(&self, init: A, mut f: impl FnMut(A, K, &V) -> A) -> A {
+        self.iter().fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+
+    /// Counts the number of entries in this map which satisfy the given predicate.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: u8| x % 3);
+    /// assert_eq!(map.count_where(|_, v| *v == 0), 86);
+    /// ```
+    pub fn count_where(&self, mut pred: impl FnMut(K, &V) -> bool) -> usize {
+        self.iter().filter(|(k, v)| pred(k.clone(), v)).count()
+    }
+
+    /// Adds together all of the values in this map, starting from [`Default::default`].
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: u8| x as u32);
+    /// assert_eq!(map.values_sum(), 255 * 256 / 2);
+    /// ```
+    pub fn values_sum(&self) -> V
+    where
+        V: Default + Clone + core::ops::Add<Output = V>,
+    {
+        self.values().fold(V::default(), |acc, v| acc + v.clone())
+    }
+
+    /// Gets the key whose value is the greatest according to the given comparison function, or
+    /// [`None`] if `K` has no values. If several keys have a greatest value, the first of them (in
+    /// order of key index) is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: u8| x % 10);
+    /// assert_eq!(map.max_key_by(|a, b| a.cmp(b)), Some(9));
+    /// assert_eq!(map.min_key_by(|a, b| a.cmp(b)), Some(0));
+    /// ```
+    pub fn max_key_by(&self, mut cmp: impl FnMut(&V, &V) -> core::cmp::Ordering) -> Option<K> {
+        self.min_key_by(|a, b| cmp(b, a))
+    }
+
+    /// Gets the key whose value is the least according to the given comparison function, or
+    /// [`None`] if `K` has no values. If several keys have a least value, the first of them (in
+    /// order of key index) is returned.
+    pub fn min_key_by(&self, mut cmp: impl FnMut(&V, &V) -> core::cmp::Ordering) -> Option<K> {
+        let values = self.0.as_slice();
+        let mut best = 0;
+        for i in 1..values.len() {
+            if cmp(&values[i], &values[best]).is_lt() {
+                best = i;
+            }
+        }
+        K::nth(best)
+    }
+
+    /// Gets the key whose value is the greatest, or [`None`] if `K` has no values. If several
+    /// keys have a greatest value, the first of them (in order of key index) is returned.
+    pub fn max_key(&self) -> Option<K>
+    where
+        V: Ord,
+    {
+        self.max_key_by(V::cmp)
+    }
+
+    /// Gets the key whose value is the least, or [`None`] if `K` has no values. If several keys
+    /// have a least value, the first of them (in order of key index) is returned.
+    pub fn min_key(&self) -> Option<K>
+    where
+        V: Ord,
+    {
+        self.min_key_by(V::cmp)
+    }
+
+    /// Combines this map with another map over the same keys by applying a function to the pair
+    /// of values for each key.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let base = ArrayMap::new(|x: bool| if x { 10 } else { 20 });
+    /// let modifier = ArrayMap::new(|x: bool| if x { 3 } else { -1 });
+    /// let stats = base.merge_with(&modifier, |a, b| a + b);
+    /// assert_eq!(stats[false], 19);
+    /// assert_eq!(stats[true], 13);
+    /// ```
+    pub fn merge_with<B, N>(
+        &self,
+        other: &ArrayMap<K, B>,
+        mut f: impl FnMut(&V, &B) -> N,
+    ) -> ArrayMap<K, N>
+    where
+        K: ArrayFinite<B> + ArrayFinite<N>,
+    {
+        let (a, b) = (self.0.as_slice(), other.0.as_slice());
+        ArrayMap(<K as ArrayFinite<N>>::Array::new(|k| unsafe {
+            f(a.get_unchecked(k), b.get_unchecked(k))
+        }))
+    }
+
+    /// Combines this map with another map over the same keys by pairing up the values for each
+    /// key.
+    pub fn zip<B: Clone>(&self, other: &ArrayMap<K, B>) -> ArrayMap<K, (V, B)>
+    where
+        V: Clone,
+        K: ArrayFinite<B> + ArrayFinite<(V, B)>,
+    {
+        self.merge_with(other, |a, b| (a.clone(), b.clone()))
+    }
+
+    /// Iterates over the keys and values of this map, in order of key index.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let map = ArrayMap::new(|x: bool| x as u8 + 10);
+    /// assert!(map.iter().eq([(false, &10), (true, &11)]));
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &V)> + ExactSizeIterator {
+        K::iter().zip(self.0.as_slice())
+    }
+
+    /// Iterates over the keys and mutable references to the values of this map, in order of key
+    /// index.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (K, &mut V)> + ExactSizeIterator {
+        K::iter().zip(self.0.as_slice_mut())
+    }
+
+    /// Iterates over the keys of this map, i.e. all values of `K`.
+    pub fn keys(&self) -> FiniteIter<K> {
+        K::iter()
+    }
+
+    /// Iterates over the values of this map, in order of key index.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: bool| x as u8 + 10);
+    /// map.values_mut().for_each(|v| *v *= 2);
+    /// assert!(map.values().eq(&[20, 22]));
+    /// ```
+    pub fn values(&self) -> core::slice::Iter<'_, V> {
+        self.0.as_slice().iter()
+    }
+
+    /// Iterates over mutable references to the values of this map, in order of key index.
+    pub fn values_mut(&mut self) -> core::slice::IterMut<'_, V> {
+        self.0.as_slice_mut().iter_mut()
+    }
 }
 
+impl<K: ArrayFinite<K>> ArrayMap<K, K> {
+    /// Iterates over all permutations of the values of `K`, each represented as a map from a value
+    /// to its image. The permutations are produced in lexicographic order, starting with the
+    /// identity.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// let perms = ArrayMap::<Ordering, Ordering>::permutations();
+    /// assert_eq!(perms.count(), 6);
+    /// ```
+    pub fn permutations() -> Permutations<K> {
+        Permutations {
+            next: Some(ArrayMap::new(|k| k)),
+        }
+    }
+}
+
+/// An iterator over all permutations of the values of a [`Finite`] type, as returned by
+/// [`ArrayMap::permutations`].
+pub struct Permutations<K: ArrayFinite<K>> {
+    next: Option<ArrayMap<K, K>>,
+}
+
+impl<K: ArrayFinite<K>> Iterator for Permutations<K>
+where
+    K::Array: Clone,
+{
+    type Item = ArrayMap<K, K>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        let values = next.0.as_slice_mut();
+
+        // Find the longest non-increasing suffix. The permutation is the last one if this is the
+        // entire sequence.
+        let mut i = values.len();
+        while i > 1 && values[i - 2] >= values[i - 1] {
+            i -= 1;
+        }
+        if i > 1 {
+            // Swap the value before the suffix with the smallest larger value in the suffix, then
+            // reverse the suffix
+            let pivot = i - 2;
+            let mut j = values.len() - 1;
+            while values[j] <= values[pivot] {
+                j -= 1;
+            }
+            values.swap(pivot, j);
+            values[pivot + 1..].reverse();
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+impl<K: ArrayFinite<K>> core::iter::FusedIterator for Permutations<K> where K::Array: Clone {}
+
 impl<K: ArrayFinite<V>, V: Default> Default for ArrayMap<K, V> {
     fn default() -> Self {
         ArrayMap(K::Array::new(|_| Default::default()))
     }
 }
 
+/// Constructs a map from key-value pairs. Keys which aren't given a value are assigned the default
+/// value, and for keys which are given multiple values, the last one is used.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let map: ArrayMap<bool, u32> = [(true, 5)].into_iter().collect();
+/// assert_eq!(map[false], 0);
+/// assert_eq!(map[true], 5);
+/// ```
+impl<K: ArrayFinite<V>, V: Default> FromIterator<(K, V)> for ArrayMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map[key] = value;
+        }
+        map
+    }
+}
+
 impl<K: ArrayFinite<V>, V> Index<K> for ArrayMap<K, V> {
     type Output = V;
     fn index(&self, index: K) -> &Self::Output {
@@ -125,6 +536,134 @@ impl<K: CompressFinite + ArrayFinite<V>, V> IndexMut<Compress<K>> for ArrayMap<K
     }
 }
 
+/// When used as a [`Set`], an [`ArrayMap`] with `bool` values contains the keys which are mapped
+/// to `true`.
+impl<K: ArrayFinite<bool>> ArrayMap<K, bool> {
+    /// Determines whether no key is mapped to `true`.
+    pub fn is_empty(&self) -> bool {
+        !self.values().any(|v| *v)
+    }
+
+    /// The number of keys which are mapped to `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut map = ArrayMap::new(|x: u8| x < 10);
+    /// assert_eq!(map.len(), 10);
+    /// map.union_with(&ArrayMap::new(|x: u8| x >= 250));
+    /// assert_eq!(map.len(), 16);
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values().filter(|v| **v).count()
+    }
+
+    /// Maps every key to `false`.
+    pub fn clear(&mut self) {
+        self.fill(false)
+    }
+
+    /// Maps every key which is mapped to `true` in `other` to `true` in this map.
+    pub fn union_with(&mut self, other: &Self) {
+        self.zip_bools(other, |a, b| a | b)
+    }
+
+    /// Maps every key which is mapped to `false` in `other` to `false` in this map.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.zip_bools(other, |a, b| a & b)
+    }
+
+    /// Maps every key which is mapped to `true` in `other` to `false` in this map.
+    pub fn difference_with(&mut self, other: &Self) {
+        self.zip_bools(other, |a, b| a & !b)
+    }
+
+    /// Applies the given binary operation to corresponding values of this map and `other`.
+    fn zip_bools(&mut self, other: &Self, mut f: impl FnMut(bool, bool) -> bool) {
+        for (a, b) in self.values_mut().zip(other.values()) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+impl<K: ArrayFinite<bool>> Set<K> for ArrayMap<K, bool> {
+    fn contains(&self, value: K) -> bool {
+        self[value]
+    }
+
+    fn include(&mut self, value: K) {
+        self[value] = true;
+    }
+
+    fn exclude(&mut self, value: K) {
+        self[value] = false;
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayMap::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        ArrayMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        ArrayMap::clear(self)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        ArrayMap::union_with(self, other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        ArrayMap::intersect_with(self, other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        ArrayMap::difference_with(self, other)
+    }
+}
+
+impl<K: CompressFinite + ArrayFinite<bool>> Set<Compress<K>> for ArrayMap<K, bool> {
+    fn contains(&self, value: Compress<K>) -> bool {
+        self[value]
+    }
+
+    fn include(&mut self, value: Compress<K>) {
+        self[value] = true;
+    }
+
+    fn exclude(&mut self, value: Compress<K>) {
+        self[value] = false;
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayMap::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        ArrayMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        ArrayMap::clear(self)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        ArrayMap::union_with(self, other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        ArrayMap::intersect_with(self, other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        ArrayMap::difference_with(self, other)
+    }
+}
+
 impl<K: ArrayFinite<V>, V> Clone for ArrayMap<K, V>
 where
     K::Array: Clone,
@@ -147,6 +686,12 @@ where
 
 impl<K: ArrayFinite<V>, V> Eq for ArrayMap<K, V> where K::Array: Eq {}
 
+impl<K: ArrayFinite<V>, V: core::hash::Hash> core::hash::Hash for ArrayMap<K, V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_slice().hash(state)
+    }
+}
+
 impl<K: ArrayFinite<V>, V> PartialOrd for ArrayMap<K, V>
 where
     K::Array: PartialOrd,
@@ -165,6 +710,62 @@ where
     }
 }
 
+impl<K: core::fmt::Debug + ArrayFinite<V>, V: core::fmt::Debug> core::fmt::Debug
+    for ArrayMap<K, V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Formats the map with one entry per line, with the values aligned.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let map = ArrayMap::new(|x: bool| if x { 100 } else { 5 });
+/// assert_eq!(map.to_string(), "false => 5\ntrue  => 100");
+/// ```
+impl<K: core::fmt::Display + ArrayFinite<V>, V: core::fmt::Display> core::fmt::Display
+    for ArrayMap<K, V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+
+        /// A [`Write`] which only counts the characters written to it.
+        struct Width(usize);
+
+        impl Write for Width {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0 += s.chars().count();
+                Ok(())
+            }
+        }
+
+        let width_of = |key: &K| {
+            let mut width = Width(0);
+            let _ = write!(width, "{}", key);
+            width.0
+        };
+        let max_width = K::iter().map(|key| width_of(&key)).max().unwrap_or(0);
+        for (i, (key, value)) in K::iter().zip(self.0.as_slice()).enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            let width = width_of(&key);
+            write!(
+                f,
+                "{}{:pad$} => {}",
+                key,
+                "",
+                value,
+                pad = max_width - width
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[test]
 fn test_map_with_key() {
     let map = ArrayMap::new(|x| if x { 1 } else { 0 });
@@ -172,3 +773,152 @@ fn test_map_with_key() {
     assert_eq!(map[false], 5);
     assert_eq!(map[true], 2);
 }
+
+#[test]
+fn test_zip() {
+    let a = ArrayMap::new(|x: u8| x);
+    let b = ArrayMap::new(|x: u8| x as u32 * 2);
+    let c = a.zip(&b);
+    assert!(c.iter().all(|(k, v)| *v == (k, k as u32 * 2)));
+    let d = c.merge_with(&a, |(x, y), z| *x as u32 + *y + *z as u32);
+    assert!(d.iter().all(|(k, v)| *v == k as u32 * 4));
+}
+
+#[test]
+fn test_transform() {
+    let mut map = ArrayMap::new(|x: bool| x as u8);
+    map.transform(|k, v| *v = *v * 10 + !k as u8);
+    assert_eq!(map, ArrayMap::from([1, 10]));
+}
+
+#[test]
+fn test_fold() {
+    use core::cmp::Ordering;
+    let map = ArrayMap::new(|x: Ordering| x as i8 * 2);
+    assert_eq!(map.values_sum(), 0);
+    assert_eq!(map.count_where(|k, v| k != Ordering::Equal && *v > 0), 1);
+    assert_eq!(map.fold(1, |acc, _, v| acc * (*v + 3)), 15);
+}
+
+#[test]
+fn test_max_key() {
+    let map = ArrayMap::new(|x: u8| (x as i32 - 100).abs() % 50);
+    assert_eq!(map.max_key(), Some(1));
+    assert_eq!(map.min_key(), Some(0));
+    assert_eq!(map.max_key_by(|a, b| b.cmp(a)), Some(0));
+    assert_eq!(map.min_key_by(|a, b| a.cmp(b).then(b.cmp(a))), Some(0));
+    let map = ArrayMap::new(|x: bool| x as u8);
+    assert_eq!(map.max_key(), Some(true));
+    assert_eq!(ArrayMap::<bool, u8>::default().max_key(), Some(false));
+    assert_eq!(
+        ArrayMap::<core::convert::Infallible, u8>::default().max_key(),
+        None
+    );
+}
+
+#[test]
+fn test_update() {
+    let mut map = ArrayMap::new(|x: u8| x);
+    assert_eq!(map.update(7, |v| v * 3), 7);
+    assert_eq!(map.replace(7, 0), 21);
+    assert_eq!(map[7], 0);
+    assert_eq!(map[8], 8);
+    map.swap(8, 9);
+    map.swap(5, 5);
+    assert_eq!((map[5], map[8], map[9]), (5, 9, 8));
+}
+
+#[test]
+fn test_fill() {
+    let mut map = ArrayMap::new(|x: bool| x as u32);
+    map.fill(7);
+    assert_eq!(map, ArrayMap::from([7, 7]));
+    let mut next = 0;
+    map.fill_with(|| {
+        next += 1;
+        next
+    });
+    assert_eq!(map, ArrayMap::from([1, 2]));
+}
+
+#[test]
+fn test_raw() {
+    let mut array = [5, 6, 7];
+    let map = ArrayMap::<core::cmp::Ordering, u8>::from_mut(&mut array);
+    map[core::cmp::Ordering::Less] = 0;
+    map.as_mut_slice()[2] = 9;
+    assert_eq!(map.as_slice(), [0, 6, 9]);
+    let map: &ArrayMap<core::cmp::Ordering, u8> = ArrayMap::from_ref(&array);
+    assert_eq!(map.into_inner(), [0, 6, 9]);
+}
+
+#[test]
+fn test_iter() {
+    let mut map = ArrayMap::new(|x: u8| x as u32 * 2);
+    for (k, v) in map.iter_mut() {
+        *v += k as u32;
+    }
+    assert!(map.iter().all(|(k, v)| *v == k as u32 * 3));
+    assert!(map.keys().eq(0..=255));
+    assert_eq!(map.values().len(), 256);
+    assert_eq!(map.iter().next_back(), Some((255, &765)));
+}
+
+#[test]
+fn test_try_from_iter() {
+    let map = ArrayMap::try_from_iter((0..=255u8).rev().map(|x| (x, x as u32))).unwrap();
+    assert!(map.iter().all(|(k, v)| k as u32 == *v));
+    assert_eq!(
+        ArrayMap::try_from_iter([(true, 1), (false, 2), (true, 3)]).err(),
+        Some(MapFromIterError::DuplicateKey(true))
+    );
+    assert!(ArrayMap::<(), ()>::try_from_iter([]).is_err());
+    assert!(ArrayMap::<core::convert::Infallible, ()>::try_from_iter([]).is_ok());
+}
+
+#[test]
+fn test_debug() {
+    extern crate alloc;
+    let map = ArrayMap::new(|x: bool| !x);
+    assert_eq!(map, map.clone());
+    assert_eq!(alloc::format!("{:?}", map), "{false: true, true: false}");
+}
+
+#[test]
+fn test_permutations() {
+    use core::cmp::Ordering;
+    let mut prev = None;
+    let mut count = 0;
+    for perm in ArrayMap::<Ordering, Ordering>::permutations() {
+        assert!(Bijection::from_map(perm).is_some());
+        assert!(prev < Some(perm));
+        prev = Some(perm);
+        count += 1;
+    }
+    assert_eq!(count, 6);
+    assert_eq!(ArrayMap::<(), ()>::permutations().count(), 1);
+    assert_eq!(
+        ArrayMap::<core::convert::Infallible, _>::permutations().count(),
+        1
+    );
+}
+
+#[test]
+fn test_set() {
+    let mut map = ArrayMap::<u8, bool>::default();
+    assert!(map.insert(200));
+    assert!(!map.insert(200));
+    map.include(compress(3u8));
+    assert!(map.contains(3u8) && map.contains(compress(200u8)));
+    assert_eq!(map.len(), 2);
+    map.exclude(200u8);
+    assert!(!map[200]);
+    let other = ArrayMap::new(|x: u8| x < 100);
+    map.union_with(&other);
+    assert_eq!(map.len(), 100);
+    map.difference_with(&ArrayMap::new(|x: u8| x < 50));
+    map.intersect_with(&other);
+    assert_eq!(map.len(), 50);
+    map.clear();
+    assert!(map.is_empty());
+}
@@ -0,0 +1,272 @@
+use crate::*;
+use core::ops::{Index, IndexMut};
+
+/// A square matrix whose rows and columns are indexed by values of type `K`, with entries in the
+/// semiring `S`. This can also be thought of as a weighted graph whose vertices are values of type
+/// `K`.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum City {
+///     A,
+///     B,
+///     C
+/// }
+///
+/// // Shortest paths
+/// let mut roads = Matrix::<City, MinPlus>::zero();
+/// roads[(City::A, City::B)] = MinPlus(3);
+/// roads[(City::B, City::C)] = MinPlus(4);
+/// roads[(City::A, City::C)] = MinPlus(10);
+/// let dists = roads.star();
+/// assert_eq!(dists[(City::A, City::C)], MinPlus(7));
+/// assert_eq!(dists[(City::C, City::A)], MinPlus::INFINITY);
+/// ```
+pub struct Matrix<K: MatrixFinite<S>, S>(ArrayMap<K, ArrayMap<K, S>>);
+
+/// A binary relation between values of type `K`, represented as a boolean [`Matrix`].
+pub type Relation<K> = Matrix<K, bool>;
+
+/// The trait required to use [`Matrix`] with a type. This is automatically implemented for all
+/// types which can be used as keys of nested [`ArrayMap`]s.
+#[doc(hidden)]
+pub trait MatrixFinite<S>: ArrayFinite<S> + ArrayFinite<ArrayMap<Self, S>> {}
+
+impl<K: ArrayFinite<S> + ArrayFinite<ArrayMap<K, S>>, S> MatrixFinite<S> for K {}
+
+/// A semiring whose elements can be used as the entries of a [`Matrix`].
+pub trait Semiring: Clone {
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Combines alternatives.
+    fn add(&self, other: &Self) -> Self;
+
+    /// Combines sequences.
+    fn mul(&self, other: &Self) -> Self;
+
+    /// The Kleene star of this element: `1 + a + a * a + a * a * a + ...`.
+    fn star(&self) -> Self;
+}
+
+/// The boolean semiring, used for reachability.
+impl Semiring for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        *self || *other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        *self && *other
+    }
+
+    fn star(&self) -> Self {
+        true
+    }
+}
+
+/// The counting semiring, used for counting paths. Arithmetic saturates, with [`u64::MAX`]
+/// standing in for an infinite count.
+impl Semiring for u64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.saturating_add(*other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.saturating_mul(*other)
+    }
+
+    fn star(&self) -> Self {
+        if *self == 0 {
+            1
+        } else {
+            u64::MAX
+        }
+    }
+}
+
+/// The tropical (min-plus) semiring over non-negative integers, used for shortest paths.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct MinPlus(pub u64);
+
+impl MinPlus {
+    /// The distance representing the absence of a path.
+    pub const INFINITY: MinPlus = MinPlus(u64::MAX);
+}
+
+impl Semiring for MinPlus {
+    fn zero() -> Self {
+        MinPlus::INFINITY
+    }
+
+    fn one() -> Self {
+        MinPlus(0)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        MinPlus(self.0.min(other.0))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        MinPlus(self.0.saturating_add(other.0))
+    }
+
+    fn star(&self) -> Self {
+        MinPlus(0)
+    }
+}
+
+impl<K: MatrixFinite<S>, S> Matrix<K, S> {
+    /// Constructs a new [`Matrix`] with entries populated using the given function of the row and
+    /// column keys.
+    pub fn new(mut f: impl FnMut(K, K) -> S) -> Self {
+        Self(ArrayMap::new(|i: K| ArrayMap::new(|j| f(i.clone(), j))))
+    }
+
+    /// Gets a row of this matrix.
+    pub fn row(&self, row: K) -> &ArrayMap<K, S> {
+        &self.0[row]
+    }
+}
+
+impl<K: MatrixFinite<S>, S: Semiring> Matrix<K, S> {
+    /// The matrix whose entries are all [`Semiring::zero`].
+    pub fn zero() -> Self {
+        Self::new(|_, _| S::zero())
+    }
+
+    /// The identity matrix.
+    pub fn identity() -> Self {
+        Self::new(|i, j| if i == j { S::one() } else { S::zero() })
+    }
+
+    /// Gets the element-wise sum of this matrix and another.
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(|i, j| self[(i.clone(), j.clone())].add(&other[(i, j)]))
+    }
+
+    /// Gets the product of this matrix and another.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::new(|i, j| {
+            let mut res = S::zero();
+            for k in K::iter() {
+                let term = self[(i.clone(), k.clone())].mul(&other[(k, j.clone())]);
+                res = res.add(&term);
+            }
+            res
+        })
+    }
+
+    /// Gets the transitive closure of this matrix: `A + A * A + A * A * A + ...`. For a boolean
+    /// matrix, this is the transitive closure of the relation.
+    pub fn plus(&self) -> Self {
+        let mut res = Self::new(|i, j| self[(i, j)].clone());
+        for k in K::iter() {
+            let pivot = res[(k.clone(), k.clone())].star();
+            res = Self::new(|i, j| {
+                let through = res[(i.clone(), k.clone())]
+                    .mul(&pivot)
+                    .mul(&res[(k.clone(), j.clone())]);
+                res[(i, j)].add(&through)
+            });
+        }
+        res
+    }
+
+    /// Gets the reflexive transitive closure of this matrix: `1 + A + A * A + A * A * A + ...`.
+    /// For a boolean matrix, this is the reachability relation. For a [`MinPlus`] matrix, this
+    /// gives the shortest distances between all pairs of keys.
+    pub fn star(&self) -> Self {
+        self.plus().add(&Self::identity())
+    }
+}
+
+impl<K: MatrixFinite<S>, S> Index<(K, K)> for Matrix<K, S> {
+    type Output = S;
+    fn index(&self, (row, col): (K, K)) -> &Self::Output {
+        &self.0[row][col]
+    }
+}
+
+impl<K: MatrixFinite<S>, S> IndexMut<(K, K)> for Matrix<K, S> {
+    fn index_mut(&mut self, (row, col): (K, K)) -> &mut Self::Output {
+        &mut self.0[row][col]
+    }
+}
+
+impl<K: MatrixFinite<S>, S> Clone for Matrix<K, S>
+where
+    ArrayMap<K, ArrayMap<K, S>>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: MatrixFinite<S>, S> PartialEq for Matrix<K, S>
+where
+    ArrayMap<K, ArrayMap<K, S>>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: MatrixFinite<S>, S> Eq for Matrix<K, S> where ArrayMap<K, ArrayMap<K, S>>: Eq {}
+
+#[cfg(test)]
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Vertex {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[test]
+fn test_reachability() {
+    use Vertex::*;
+    let edges = Relation::new(|i, j| matches!((i, j), (A, B) | (B, C) | (C, B)));
+    let reach = edges.star();
+    assert!(reach[(A, C)]);
+    assert!(reach[(D, D)]);
+    assert!(!reach[(C, A)]);
+    let plus = edges.plus();
+    assert!(!plus[(A, A)]);
+    assert!(plus[(B, B)]);
+}
+
+#[test]
+fn test_path_counting() {
+    use Vertex::*;
+    let edges =
+        Matrix::new(|i, j| matches!((i, j), (A, B) | (A, C) | (B, D) | (C, D) | (A, D)) as u64);
+    let paths = edges.star();
+    assert_eq!(paths[(A, D)], 3);
+    assert_eq!(paths[(A, A)], 1);
+    assert_eq!(paths[(D, A)], 0);
+    assert_eq!(edges.mul(&edges)[(A, D)], 2);
+    let cycle = Matrix::new(|i, j| matches!((i, j), (A, B) | (B, A)) as u64);
+    assert_eq!(cycle.star()[(A, B)], u64::MAX);
+}
@@ -0,0 +1,125 @@
+use crate::*;
+use core::cell::OnceCell;
+
+/// A complete mapping from keys of type `K` to values of type `V`, where each value is computed
+/// from a stored function the first time it is accessed. Requires the `alloc` feature.
+///
+/// This is useful for caching an expensive function over a type with many values, of which only
+/// a few are ever needed.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// use core::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let memo = MemoMap::new(|x: u16| {
+///     calls.set(calls.get() + 1);
+///     x as u32 * x as u32
+/// });
+/// assert_eq!(*memo.get(300), 90000);
+/// assert_eq!(*memo.get(300), 90000);
+/// assert_eq!(calls.get(), 1);
+/// assert_eq!(memo.peek(301), None);
+/// ```
+pub struct MemoMap<K: Finite, V, F: Fn(K) -> V> {
+    values: VecMap<K, OnceCell<V>>,
+    f: F,
+}
+
+impl<K: Finite, V, F: Fn(K) -> V> MemoMap<K, V, F> {
+    /// Constructs a new [`MemoMap`] which computes its values using the given function. No values
+    /// are computed until they are accessed.
+    pub fn new(f: F) -> Self {
+        MemoMap {
+            values: VecMap::new(|_| OnceCell::new()),
+            f,
+        }
+    }
+
+    /// Gets the value for the given key, computing it if it hasn't been computed yet.
+    pub fn get(&self, key: K) -> &V {
+        self.values[key.clone()].get_or_init(|| (self.f)(key))
+    }
+
+    /// Gets a mutable reference to the value for the given key, computing it if it hasn't been
+    /// computed yet.
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        let cell = &mut self.values[key.clone()];
+        if cell.get().is_none() {
+            let _ = cell.set((self.f)(key));
+        }
+        cell.get_mut().unwrap()
+    }
+
+    /// Gets the value for the given key if it has already been computed, without computing it
+    /// otherwise.
+    pub fn peek(&self, key: K) -> Option<&V> {
+        self.values[key].get()
+    }
+
+    /// Determines whether the value for the given key has been computed.
+    pub fn is_computed(&self, key: K) -> bool {
+        self.peek(key).is_some()
+    }
+
+    /// The number of values which have been computed.
+    pub fn num_computed(&self) -> usize {
+        self.values
+            .values()
+            .filter(|cell| cell.get().is_some())
+            .count()
+    }
+
+    /// Iterates over the keys and values which have been computed, in order of key index.
+    pub fn iter_computed(&self) -> impl Iterator<Item = (K, &V)> {
+        self.values
+            .iter()
+            .filter_map(|(k, cell)| Some((k, cell.get()?)))
+    }
+
+    /// Discards all computed values, so that they will be recomputed when next accessed.
+    pub fn clear(&mut self) {
+        self.values.values_mut().for_each(|cell| {
+            cell.take();
+        });
+    }
+}
+
+/// Wraps the given function in a cache, so that it is evaluated at most once for each value of
+/// `K`. Requires the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let fib = memoize(|n: u8| (0..n).fold((0u64, 1u64), |(a, b), _| (b, a + b)).0);
+/// assert_eq!(fib(90), 2880067194370816120);
+/// assert_eq!(fib(90), 2880067194370816120);
+/// ```
+pub fn memoize<K: Finite, V: Clone>(f: impl Fn(K) -> V) -> impl Fn(K) -> V {
+    let memo = MemoMap::new(f);
+    move |key| memo.get(key).clone()
+}
+
+#[test]
+fn test_memo_map() {
+    use core::cell::Cell;
+    let calls = Cell::new(0);
+    let mut memo = MemoMap::new(|x: u8| {
+        calls.set(calls.get() + 1);
+        alloc::vec![x; 3]
+    });
+    assert_eq!(memo.num_computed(), 0);
+    assert_eq!(memo.get(5), &[5, 5, 5]);
+    memo.get_mut(5).push(6);
+    memo.get_mut(7).clear();
+    assert_eq!(calls.get(), 2);
+    assert!(memo.is_computed(7) && !memo.is_computed(6));
+    assert!(memo
+        .iter_computed()
+        .eq([(5, &alloc::vec![5, 5, 5, 6]), (7, &alloc::vec![])]));
+    memo.clear();
+    assert_eq!(memo.num_computed(), 0);
+    assert_eq!(memo.get(5).len(), 3);
+    assert_eq!(calls.get(), 3);
+}
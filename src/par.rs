@@ -0,0 +1,49 @@
+use crate::uint::Unsigned;
+use crate::*;
+use rayon::prelude::*;
+use std::vec::Vec;
+
+/// Gets a parallel iterator over all of the values of a [`Finite`] type. Requires the `rayon`
+/// feature.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// use rayon::prelude::*;
+/// let sum: u64 = par_values::<u16>().map(|x| x as u64).sum();
+/// assert_eq!(sum, 65535 * 65536 / 2);
+/// ```
+pub fn par_values<T: Finite + Send>() -> impl IndexedParallelIterator<Item = T> {
+    (0..T::COUNT)
+        .into_par_iter()
+        .map(|index| unsafe { T::nth_unchecked(index) })
+}
+
+impl<K: ArrayFinite<V>, V: Send> ArrayMap<K, V> {
+    /// Constructs a new [`ArrayMap`] with initial values populated using the given function, which
+    /// is evaluated in parallel. Requires the `rayon` feature.
+    pub fn par_new(f: impl Fn(K) -> V + Sync + Send) -> Self {
+        let values: Vec<V> = (0..K::COUNT)
+            .into_par_iter()
+            .map(|index| f(unsafe { K::nth_unchecked(index) }))
+            .collect();
+        let mut values = values.into_iter();
+        ArrayMap::new(|_| unsafe { values.next().unwrap_unchecked() })
+    }
+}
+
+impl<T: BitmapFinite> BitmapSet<T>
+where
+    T::Bitmap: Send,
+{
+    /// Constructs a new [`BitmapSet`] containing the values for which the given function returns
+    /// `true`. The function is evaluated in parallel. Requires the `rayon` feature.
+    pub fn par_new(f: impl Fn(T) -> bool + Sync + Send) -> Self {
+        let bitmap = (0..T::COUNT)
+            .into_par_iter()
+            .filter(|index| f(unsafe { T::nth_unchecked(*index) }))
+            .map(T::Bitmap::one_at)
+            .reduce(|| T::Bitmap::ZERO, |a, b| a | b);
+        unsafe { BitmapSet::from_bits_unchecked(bitmap) }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::array::Array;
+use crate::*;
+use core::mem::MaybeUninit;
+
+/// A partial mapping from keys of type `K` to values of type `V`, implemented using an array
+/// indexed by [`Finite::index_of`] of the key, along with a [`BitmapSet`] tracking which keys
+/// have values.
+///
+/// Compared to an [`ArrayMap`] with [`Option`] values, this avoids the space overhead of
+/// [`Option`] for each entry, and allows the present entries to be enumerated quickly.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// enum Slot {
+///     Head,
+///     Body,
+///     Hand(bool),
+/// }
+///
+/// let mut map = PartialArrayMap::new();
+/// assert_eq!(map.insert(Slot::Hand(true), "sword"), None);
+/// assert_eq!(map.insert(Slot::Head, "helmet"), None);
+/// assert_eq!(map.insert(Slot::Hand(true), "axe"), Some("sword"));
+/// assert_eq!(map.get(Slot::Body), None);
+/// assert_eq!(map.len(), 2);
+/// assert!(map.keys().eq([Slot::Head, Slot::Hand(true)]));
+/// ```
+pub struct PartialArrayMap<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V> {
+    values: <K as ArrayFinite<MaybeUninit<V>>>::Array,
+    keys: BitmapSet<K>,
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V> PartialArrayMap<K, V> {
+    /// Constructs a new [`PartialArrayMap`] with no entries.
+    pub fn new() -> Self {
+        PartialArrayMap {
+            values: Array::new(|_| MaybeUninit::uninit()),
+            keys: BitmapSet::none(),
+        }
+    }
+
+    /// The number of keys which have a value in this map.
+    pub fn len(&self) -> usize {
+        self.keys.size()
+    }
+
+    /// Determines whether this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_none()
+    }
+
+    /// The set of keys which have a value in this map.
+    pub fn keys(&self) -> BitmapSet<K> {
+        self.keys
+    }
+
+    /// Determines whether the given key has a value in this map.
+    pub fn contains_key(&self, key: K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Gets the value for the given key, or [`None`] if it doesn't have one.
+    pub fn get(&self, key: K) -> Option<&V> {
+        if self.keys.contains(key.clone()) {
+            Some(unsafe { self.slot(K::index_of(key)).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the value for the given key, or [`None`] if it doesn't have
+    /// one.
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        if self.keys.contains(key.clone()) {
+            Some(unsafe { self.slot_mut(K::index_of(key)).assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Sets the value for the given key, returning the previous value if there was one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let index = K::index_of(key.clone());
+        let is_new = self.keys.insert(key);
+        let slot = unsafe { self.slot_mut(index) };
+        if is_new {
+            slot.write(value);
+            None
+        } else {
+            Some(unsafe { core::mem::replace(slot, MaybeUninit::new(value)).assume_init() })
+        }
+    }
+
+    /// Removes the value for the given key, returning it if there was one.
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let index = K::index_of(key.clone());
+        if self.keys.remove(key) {
+            Some(unsafe { self.slot_mut(index).assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes all entries from this map.
+    pub fn clear(&mut self) {
+        while let Some(key) = self.keys.pop_first() {
+            unsafe { self.slot_mut(K::index_of(key)).assume_init_drop() };
+        }
+    }
+
+    /// Iterates over the keys and values of the entries in this map, in order of key index.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &V)> {
+        self.keys.map(move |key| {
+            let value = unsafe { self.slot(K::index_of(key.clone())).assume_init_ref() };
+            (key, value)
+        })
+    }
+
+    /// Gets the storage slot at the given index.
+    ///
+    /// # Safety
+    /// `index` must be less than `K::COUNT`.
+    unsafe fn slot(&self, index: usize) -> &MaybeUninit<V> {
+        self.values.as_slice().get_unchecked(index)
+    }
+
+    /// Gets the storage slot at the given index.
+    ///
+    /// # Safety
+    /// `index` must be less than `K::COUNT`.
+    unsafe fn slot_mut(&mut self, index: usize) -> &mut MaybeUninit<V> {
+        self.values.as_slice_mut().get_unchecked_mut(index)
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V> Drop for PartialArrayMap<K, V> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V> Default for PartialArrayMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V> FromIterator<(K, V)>
+    for PartialArrayMap<K, V>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V: Clone> Clone for PartialArrayMap<K, V> {
+    fn clone(&self) -> Self {
+        self.iter().map(|(k, v)| (k, v.clone())).collect()
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V: PartialEq> PartialEq
+    for PartialArrayMap<K, V>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.iter().zip(other.iter()).all(|(a, b)| a.1 == b.1)
+    }
+}
+
+impl<K: ArrayFinite<MaybeUninit<V>> + BitmapFinite, V: Eq> Eq for PartialArrayMap<K, V> {}
+
+impl<K: core::fmt::Debug + ArrayFinite<MaybeUninit<V>> + BitmapFinite, V: core::fmt::Debug>
+    core::fmt::Debug for PartialArrayMap<K, V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[test]
+fn test_partial_array_map() {
+    use core::cell::Cell;
+    use core::cmp::Ordering;
+    #[derive(Clone, PartialEq)]
+    struct Counted<'a>(&'a Cell<usize>);
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+    let drops = Cell::new(0);
+    let mut map = PartialArrayMap::<Ordering, _>::new();
+    assert!(map.is_empty());
+    assert!(map.insert(Ordering::Greater, Counted(&drops)).is_none());
+    assert!(map.insert(Ordering::Less, Counted(&drops)).is_none());
+    assert!(map.insert(Ordering::Less, Counted(&drops)).is_some());
+    assert_eq!(drops.get(), 1);
+    assert!(map.get(Ordering::Equal).is_none());
+    assert!(map.get_mut(Ordering::Greater).is_some());
+    assert!(map.contains_key(Ordering::Less));
+    let copy = map.clone();
+    assert!(copy == map);
+    assert!(map.remove(Ordering::Less).is_some());
+    assert!(map.remove(Ordering::Less).is_none());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(map.len(), 1);
+    assert!(map.iter().map(|(k, _)| k).eq([Ordering::Greater]));
+    assert!(copy != map);
+    drop(copy);
+    drop(map);
+    assert_eq!(drops.get(), 5);
+}
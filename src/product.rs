@@ -0,0 +1,104 @@
+use crate::*;
+
+/// Iterates over all pairs of values of two [`Finite`] types, in lexicographic order. This yields
+/// the same values as `<(A, B)>::iter()`, but is faster since it doesn't need to decompose an
+/// index for each pair.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert!(pairs::<bool, u8>().eq(<(bool, u8)>::iter()));
+/// ```
+pub fn pairs<A: Finite, B: Finite>() -> Pairs<A, B> {
+    Product::new(A::iter())
+}
+
+/// Iterates over all triples of values of three [`Finite`] types, in lexicographic order. This
+/// yields the same values as `<(A, B, C)>::iter()`, but is faster since it doesn't need to
+/// decompose an index for each triple.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert!(triples::<bool, u8, bool>().eq(<(bool, u8, bool)>::iter()));
+/// ```
+pub fn triples<A: Finite, B: Finite, C: Finite>() -> Triples<A, B, C> {
+    Triples(Product::new(pairs()))
+}
+
+/// An iterator over all pairs of values of two [`Finite`] types, as returned by [`pairs`].
+pub type Pairs<A, B> = Product<FiniteIter<A>, B>;
+
+/// An iterator which pairs each item of an outer iterator with every value of a [`Finite`] type.
+pub struct Product<I: Iterator, B: Finite> {
+    outer: I,
+    current: Option<I::Item>,
+    inner: FiniteIter<B>,
+}
+
+impl<I: Iterator, B: Finite> Product<I, B> {
+    /// Constructs a [`Product`] from the given outer iterator.
+    pub fn new(mut outer: I) -> Self {
+        let current = if B::COUNT > 0 { outer.next() } else { None };
+        Self {
+            outer,
+            current,
+            inner: B::iter(),
+        }
+    }
+}
+
+impl<I: Iterator, B: Finite> Iterator for Product<I, B>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_ref()?;
+        if let Some(b) = self.inner.next() {
+            return Some((current.clone(), b));
+        }
+        self.current = self.outer.next();
+        self.inner = B::iter();
+        let b = self.inner.next()?;
+        Some((self.current.clone()?, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.current.is_none() {
+            return (0, Some(0));
+        }
+        let (lo, hi) = self.outer.size_hint();
+        let rem = self.inner.len();
+        let lo = lo.saturating_mul(B::COUNT).saturating_add(rem);
+        let hi = hi.and_then(|hi| hi.checked_mul(B::COUNT)?.checked_add(rem));
+        (lo, hi)
+    }
+}
+
+/// An iterator over all triples of values of three [`Finite`] types, as returned by [`triples`].
+pub struct Triples<A: Finite, B: Finite, C: Finite>(Product<Pairs<A, B>, C>);
+
+impl<A: Finite, B: Finite, C: Finite> Iterator for Triples<A, B, C> {
+    type Item = (A, B, C);
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((a, b), c) = self.0.next()?;
+        Some((a, b, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+#[test]
+fn test_product() {
+    let mut iter = pairs::<bool, u8>();
+    assert_eq!(iter.size_hint(), (512, Some(512)));
+    assert_eq!(iter.nth(256), Some((true, 0)));
+    assert_eq!(iter.size_hint(), (255, Some(255)));
+    assert_eq!(pairs::<u8, core::convert::Infallible>().count(), 0);
+    assert_eq!(pairs::<core::convert::Infallible, u8>().count(), 0);
+    assert_eq!(triples::<bool, (), bool>().size_hint(), (4, Some(4)));
+    assert!(triples::<bool, i8, bool>().eq(<(bool, i8, bool)>::iter()));
+}
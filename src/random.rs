@@ -0,0 +1,101 @@
+use crate::uint::Unsigned;
+use crate::*;
+use rand::Rng;
+
+impl<T: BitmapFinite> BitmapSet<T> {
+    /// Constructs a uniformly random set of values of `T`, where each value is included with
+    /// probability one half. Requires the `rand` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use rand::SeedableRng;
+    /// let mut rng = rand::rngs::SmallRng::seed_from_u64(1);
+    /// let set = BitmapSet::<bool>::random(&mut rng);
+    /// assert!(set.size() <= 2);
+    /// ```
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        // Fill the bitmap 64 bits at a time
+        let mut bitmap = T::Bitmap::ZERO;
+        for start in (0..T::COUNT).step_by(64) {
+            let mut word: u64 = rng.gen();
+            while word != 0 {
+                let index = start + word.trailing_zeros() as usize;
+                if index >= T::COUNT {
+                    break;
+                }
+                bitmap = bitmap | T::Bitmap::one_at(index);
+                word &= word - 1;
+            }
+        }
+        BitmapSet::from_bits_truncate(bitmap)
+    }
+
+    /// Constructs a random set of values of `T`, where each value is included independently with
+    /// probability `p`. Requires the `rand` feature.
+    ///
+    /// # Panics
+    /// Panics if `p` is not in the range `0.0..=1.0`.
+    pub fn random_with_density<R: Rng + ?Sized>(rng: &mut R, p: f64) -> Self {
+        BitmapSet::new(|_| rng.gen_bool(p))
+    }
+
+    /// Selects a uniformly random value from this set, or returns [`None`] if it is empty.
+    /// Requires the `rand` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// use rand::SeedableRng;
+    /// let mut rng = rand::rngs::SmallRng::seed_from_u64(1);
+    /// let set = BitmapSet::new(|x: Ordering| x != Ordering::Equal);
+    /// assert_ne!(set.choose(&mut rng), Some(Ordering::Equal));
+    /// assert_eq!(BitmapSet::<Ordering>::none().choose(&mut rng), None);
+    /// ```
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let size = self.size();
+        if size > 0 {
+            self.select(rng.gen_range(0..size))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+struct Pair(bool, bool);
+
+#[test]
+fn test_choose() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+    let set = BitmapSet::new(|x: Pair| x.0 || x.1);
+    let mut counts = ArrayMap::<Pair, u32>::default();
+    for _ in 0..3000 {
+        counts[set.choose(&mut rng).unwrap()] += 1;
+    }
+    for x in Pair::iter() {
+        if set.contains(x) {
+            assert!((800..1200).contains(&counts[x]));
+        } else {
+            assert_eq!(counts[x], 0);
+        }
+    }
+}
+
+#[test]
+fn test_random() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+    let mut counts = [0; 16];
+    for _ in 0..3200 {
+        counts[BitmapSet::<Pair>::random(&mut rng).to_bits() as usize] += 1;
+    }
+    assert!(counts.iter().all(|count| (100..300).contains(count)));
+    let set = BitmapSet::<Pair>::random_with_density(&mut rng, 0.0);
+    assert!(set.is_none());
+    let set = BitmapSet::<Pair>::random_with_density(&mut rng, 1.0);
+    assert_eq!(set, BitmapSet::all());
+}
@@ -0,0 +1,121 @@
+use crate::*;
+
+/// Gets the number of steps between two values of a [`Finite`] type, i.e. the absolute difference
+/// between their indices.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert_eq!(distance((false, true), (true, true)), 2);
+/// assert_eq!(distance(200u8, 10u8), 190);
+/// ```
+pub fn distance<T: Finite>(a: T, b: T) -> usize {
+    let a = T::index_of(a);
+    let b = T::index_of(b);
+    a.abs_diff(b)
+}
+
+/// Gets the value of a [`Finite`] type halfway between two values, i.e. the value whose index is
+/// the average of their indices, rounded down.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert_eq!(midpoint(10u8, 20u8), 15);
+/// assert_eq!(midpoint(-1i8, -4i8), -3);
+/// ```
+pub fn midpoint<T: Finite>(a: T, b: T) -> T {
+    let a = T::index_of(a);
+    let b = T::index_of(b);
+    let index = a.min(b) + a.abs_diff(b) / 2;
+    unsafe { T::nth_unchecked(index) }
+}
+
+/// Determines whether the given predicate holds for all values of a [`Finite`] type, stopping at
+/// the first value for which it doesn't.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert!(for_all(|(a, b): (bool, bool)| (a && b) == !(!a || !b)));
+/// assert!(!for_all(|x: u8| x < 255));
+/// ```
+pub fn for_all<T: Finite>(pred: impl FnMut(T) -> bool) -> bool {
+    T::iter().all(pred)
+}
+
+/// Determines whether the given predicate holds for any value of a [`Finite`] type, stopping at
+/// the first value for which it does.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert!(exists(|x: u8| x.count_ones() == 8));
+/// assert!(!exists(|(a, b): (bool, bool)| a && !a && b));
+/// ```
+pub fn exists<T: Finite>(pred: impl FnMut(T) -> bool) -> bool {
+    T::iter().any(pred)
+}
+
+/// Finds the first value of a [`Finite`] type for which the given predicate holds, e.g. a
+/// counterexample to a property which should hold for all values. Returns [`None`] if there is no
+/// such value.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert_eq!(find(|x: u8| x.wrapping_mul(3) == 1), Some(171));
+/// assert_eq!(find(|x: i8| x.checked_abs().is_none()), Some(i8::MIN));
+/// ```
+pub fn find<T: Finite>(mut pred: impl FnMut(T) -> bool) -> Option<T> {
+    T::iter().find(|value| pred(value.clone()))
+}
+
+/// Finds the first value of a [`Finite`] type for which the given predicate holds, assuming the
+/// predicate is monotone (i.e. it holds for all values after that one, and none before). This uses
+/// a binary search, so it only requires a logarithmic number of evaluations of the predicate.
+///
+/// Returns [`None`] if the predicate doesn't hold for any value.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// assert_eq!(partition_point(|x: u16| (x as u32).pow(2) >= 1_000_000), Some(1000));
+/// assert_eq!(partition_point(|x: i8| x > 127), None);
+/// ```
+pub fn partition_point<T: Finite>(mut pred: impl FnMut(T) -> bool) -> Option<T> {
+    let mut lo = 0;
+    let mut hi = T::COUNT;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(unsafe { T::nth_unchecked(mid) }) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    T::nth(lo)
+}
+
+#[test]
+fn test_partition_point() {
+    assert_eq!(partition_point(|x: u16| x >= 12345), Some(12345));
+    assert_eq!(partition_point(|_: u8| true), Some(0));
+    assert_eq!(partition_point(|_: u8| false), None);
+    assert_eq!(partition_point(|_: core::convert::Infallible| true), None);
+    let mut evals = 0;
+    partition_point(|x: (u16, u8)| {
+        evals += 1;
+        x >= (40000, 7)
+    });
+    assert!(evals <= 24);
+}
+
+#[test]
+fn test_midpoint() {
+    assert_eq!(midpoint(u16::MIN, u16::MAX), 32767);
+    assert!(!midpoint(false, true));
+    assert!(midpoint(true, true));
+    assert_eq!(distance(i16::MIN, i16::MAX), 65535);
+    assert_eq!(distance((), ()), 0);
+}
@@ -24,6 +24,14 @@ use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign,
 /// assert!(set.contains(MyType::B(false)));
 /// assert!(!set.contains(MyType::C(false, true)));
 /// ```
+///
+/// Bitmaps are limited to 4096 bits, so types with more values than that can't be used with
+/// [`BitmapSet`]. Consider [`ArraySet`] for these instead.
+///
+/// ```compile_fail
+/// use cantor::*;
+/// let set = BitmapSet::<u16>::none();
+/// ```
 pub struct BitmapSet<T: BitmapFinite>(T::Bitmap);
 
 /// The trait required to use [`BitmapSet`] with a type.
@@ -50,7 +58,7 @@ impl<T: BitmapFinite> BitmapSet<T> {
     pub fn new(mut f: impl FnMut(T) -> bool) -> Self {
         let mut bitmap = T::Bitmap::ZERO;
         for i in 0..T::COUNT {
-            if f(unsafe { T::nth(i).unwrap_unchecked() }) {
+            if f(unsafe { T::nth_unchecked(i) }) {
                 bitmap = bitmap | T::Bitmap::one_at(i);
             }
         }
@@ -63,10 +71,79 @@ impl<T: BitmapFinite> BitmapSet<T> {
     }
 
     /// The empty set.
-    pub fn none() -> Self {
+    pub const fn none() -> Self {
         BitmapSet(T::Bitmap::ZERO)
     }
 
+    /// Constructs a set from its underlying bitmap, where bit `i` is set iff the value with index
+    /// `i` is in the set. Returns a [`TryFromIndexError`] for the highest bit if any bit at or
+    /// above [`Finite::COUNT`] is set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// assert_eq!(BitmapSet::<bool>::from_bits(0b10), Ok(BitmapSet::only(true)));
+    /// assert_eq!(BitmapSet::<bool>::from_bits(0b110).unwrap_err().index(), 2);
+    /// ```
+    pub fn from_bits(bits: T::Bitmap) -> Result<Self, TryFromIndexError> {
+        match (bits & !T::Bitmap::ones(T::COUNT)).last_one() {
+            Some(index) => Err(TryFromIndexError::new(index, T::COUNT)),
+            None => Ok(BitmapSet(bits)),
+        }
+    }
+
+    /// Constructs a set from its underlying bitmap, where bit `i` is set iff the value with index
+    /// `i` is in the set. Any bits at or above [`Finite::COUNT`] are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// assert_eq!(BitmapSet::<bool>::from_bits_truncate(0b110), BitmapSet::only(true));
+    /// ```
+    pub fn from_bits_truncate(bits: T::Bitmap) -> Self {
+        BitmapSet(bits & T::Bitmap::ones(T::COUNT))
+    }
+
+    /// Constructs a set directly from its underlying bitmap, where bit `i` is set iff the value
+    /// with index `i` is in the set. This can be used to define sets at compile time.
+    ///
+    /// # Safety
+    /// No bits at or above [`Finite::COUNT`] may be set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    ///
+    /// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+    /// enum MyType {
+    ///     A,
+    ///     B(bool),
+    ///     C(bool, bool)
+    /// }
+    ///
+    /// static ALLOWED: BitmapSet<MyType> = unsafe { BitmapSet::from_bits_unchecked(0b0000101) };
+    /// assert!(ALLOWED.contains(MyType::A));
+    /// assert!(ALLOWED.contains(MyType::B(true)));
+    /// assert_eq!(ALLOWED.size(), 2);
+    /// ```
+    pub const unsafe fn from_bits_unchecked(bits: T::Bitmap) -> Self {
+        BitmapSet(bits)
+    }
+
+    /// Gets the underlying bitmap for this set, where bit `i` is set iff the value with index `i`
+    /// is in the set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = BitmapSet::<bool>::only(true);
+    /// assert_eq!(set.to_bits(), 0b10);
+    /// assert_eq!(BitmapSet::from_bits(set.to_bits()), Ok(set));
+    /// ```
+    pub const fn to_bits(&self) -> T::Bitmap {
+        self.0
+    }
+
     /// The set consisting of only the given value.
     pub fn only(value: T) -> Self {
         BitmapSet(T::Bitmap::one_at(T::index_of(value)))
@@ -81,6 +158,347 @@ impl<T: BitmapFinite> BitmapSet<T> {
     pub fn is_none(&self) -> bool {
         self.0 == T::Bitmap::ZERO
     }
+
+    /// Determines whether this is the empty set. This is equivalent to [`BitmapSet::is_none`].
+    pub fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    /// The number of values in this set. This is equivalent to [`BitmapSet::size`].
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Removes all values from this set.
+    pub fn clear(&mut self) {
+        *self = Self::none();
+    }
+
+    /// Adds all values in `other` to this set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut set = BitmapSet::only(false);
+    /// set.union_with(&BitmapSet::only(true));
+    /// assert_eq!(set.len(), 2);
+    /// set.difference_with(&BitmapSet::only(false));
+    /// assert_eq!(set, BitmapSet::only(true));
+    /// set.intersect_with(&BitmapSet::only(false));
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn union_with(&mut self, other: &Self) {
+        *self |= *other;
+    }
+
+    /// Removes all values from this set which are not in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        *self &= *other;
+    }
+
+    /// Removes all values from this set which are in `other`.
+    pub fn difference_with(&mut self, other: &Self) {
+        *self -= *other;
+    }
+
+    /// Determines whether this is the set of all possible values of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut set = BitmapSet::only(false);
+    /// assert!(!set.is_all());
+    /// set.include(true);
+    /// assert!(set.is_all());
+    /// ```
+    pub fn is_all(&self) -> bool {
+        self.0 == T::Bitmap::ones(T::COUNT)
+    }
+
+    /// The number of values in both this set and `other`, computed without constructing their
+    /// intersection.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let a = BitmapSet::new(|x: u8| x < 100);
+    /// let b = BitmapSet::new(|x: u8| x >= 40);
+    /// assert_eq!(a.intersection_size(&b), 60);
+    /// assert_eq!(a.union_size(&b), 256);
+    /// assert_eq!(a.difference_size(&b), 40);
+    /// ```
+    pub fn intersection_size(&self, other: &Self) -> usize {
+        T::Bitmap::count_ones(self.0 & other.0)
+    }
+
+    /// The number of values in either this set or `other`, computed without constructing their
+    /// union.
+    pub fn union_size(&self, other: &Self) -> usize {
+        T::Bitmap::count_ones(self.0 | other.0)
+    }
+
+    /// The number of values in this set but not in `other`, computed without constructing their
+    /// difference.
+    pub fn difference_size(&self, other: &Self) -> usize {
+        T::Bitmap::count_ones(self.0 & !other.0)
+    }
+
+    /// Determines whether every value in this set is also in `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// assert!(BitmapSet::only(true).is_subset(&BitmapSet::all()));
+    /// assert!(!BitmapSet::all().is_subset(&BitmapSet::only(true)));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0 & !other.0 == T::Bitmap::ZERO
+    }
+
+    /// Determines whether every value in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Determines whether this set has no values in common with `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// assert!(BitmapSet::only(true).is_disjoint(&BitmapSet::only(false)));
+    /// assert!(!BitmapSet::only(true).is_disjoint(&BitmapSet::all()));
+    /// ```
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0 & other.0 == T::Bitmap::ZERO
+    }
+
+    /// Removes all values from this set for which the given predicate returns `false`. The
+    /// predicate is only evaluated on values in the set, in increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// let mut set = BitmapSet::new(|x: Ordering| x != Ordering::Less);
+    /// set.retain(|x| x != Ordering::Greater);
+    /// assert_eq!(set, BitmapSet::only(Ordering::Equal));
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(T) -> bool) {
+        for value in *self {
+            if !f(value.clone()) {
+                self.exclude(value);
+            }
+        }
+    }
+
+    /// Gets the set of values produced by applying the given function to each value in this set.
+    /// Unlike [`Iterator::map`], this collects the results into a [`BitmapSet`].
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// let set = BitmapSet::new(|x: Ordering| x != Ordering::Equal);
+    /// assert_eq!(set.map_set(|x| x == Ordering::Less), BitmapSet::all());
+    /// ```
+    pub fn map_set<U: BitmapFinite>(self, mut f: impl FnMut(T) -> U) -> BitmapSet<U> {
+        self.filter_map_set(|value| Some(f(value)))
+    }
+
+    /// Gets the set of values produced by applying the given function to each value in this set,
+    /// excluding [`None`]s. Unlike [`Iterator::filter_map`], this collects the results into a
+    /// [`BitmapSet`].
+    pub fn filter_map_set<U: BitmapFinite>(
+        self,
+        mut f: impl FnMut(T) -> Option<U>,
+    ) -> BitmapSet<U> {
+        let mut res = BitmapSet::none();
+        for value in self {
+            if let Some(value) = f(value) {
+                res.include(value);
+            }
+        }
+        res
+    }
+
+    /// The number of values in this set which are less than the given value.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::BitmapSet;
+    /// let set = BitmapSet::<bool>::all();
+    /// assert_eq!(set.rank(false), 0);
+    /// assert_eq!(set.rank(true), 1);
+    /// ```
+    pub fn rank(&self, value: T) -> usize {
+        T::Bitmap::count_ones(self.0 & T::Bitmap::ones(T::index_of(value)))
+    }
+
+    /// Gets the value in this set which has exactly `n` smaller values in the set, or returns
+    /// [`None`] if the set does not have more than `n` values. This is the inverse of
+    /// [`BitmapSet::rank`].
+    pub fn select(&self, n: usize) -> Option<T> {
+        let index = self.0.select_one(n)?;
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Gets the smallest value in this set, or [`None`] if it is empty.
+    ///
+    /// Note that this shadows [`Finite::first`] for [`BitmapSet`], which can still be called as
+    /// `<BitmapSet<T> as Finite>::first()`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = BitmapSet::new(|x: u8| x % 10 == 7);
+    /// assert_eq!(set.first(), Some(7));
+    /// assert_eq!(set.last(), Some(247));
+    /// assert_eq!(set.size(), 25);
+    /// ```
+    pub fn first(self) -> Option<T> {
+        let index = self.0.first_one()?;
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Gets the largest value in this set, or [`None`] if it is empty.
+    ///
+    /// Note that this shadows [`Finite::last`] for [`BitmapSet`], which can still be called as
+    /// `<BitmapSet<T> as Finite>::last()`.
+    pub fn last(self) -> Option<T> {
+        let index = self.0.last_one()?;
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Gets the smallest value in this set, or [`None`] if it is empty. This is equivalent to
+    /// [`BitmapSet::first`].
+    ///
+    /// Without this, `set.min()` would be ambiguous between [`Iterator::min`] and [`Ord::min`].
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = BitmapSet::new(|x: u8| x > 10);
+    /// assert_eq!(set.min(), Some(11));
+    /// assert_eq!(set.max(), Some(255));
+    /// assert_eq!(BitmapSet::<u8>::none().min(), None);
+    /// ```
+    pub fn min(self) -> Option<T> {
+        self.first()
+    }
+
+    /// Gets the largest value in this set, or [`None`] if it is empty. This is equivalent to
+    /// [`BitmapSet::last`].
+    ///
+    /// Without this, `set.max()` would be ambiguous between [`Iterator::max`] and [`Ord::max`].
+    pub fn max(self) -> Option<T> {
+        self.last()
+    }
+
+    /// Removes and returns the smallest value in this set, or [`None`] if it is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut set = BitmapSet::all();
+    /// assert_eq!(set.pop_first(), Some(false));
+    /// assert_eq!(set.pop_first(), Some(true));
+    /// assert_eq!(set.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        let index = self.0.first_one()?;
+        self.0 = self.0 & !T::Bitmap::one_at(index);
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Removes and returns the largest value in this set, or [`None`] if it is empty.
+    pub fn pop_last(&mut self) -> Option<T> {
+        let index = self.0.last_one()?;
+        self.0 = self.0 & !T::Bitmap::one_at(index);
+        Some(unsafe { T::nth_unchecked(index) })
+    }
+
+    /// Iterates over all sets of exactly `k` values, in increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut pairs = BitmapSet::<core::cmp::Ordering>::combinations(2);
+    /// assert_eq!(pairs.next().unwrap().size(), 2);
+    /// assert_eq!(pairs.count(), 2);
+    /// ```
+    pub fn combinations(k: usize) -> Combinations<T> {
+        Self::all().subsets_of_size(k)
+    }
+
+    /// Iterates over all subsets of this set with exactly `k` values, in increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// let set = BitmapSet::new(|x: Ordering| x != Ordering::Equal);
+    /// let mut subsets = set.subsets_of_size(1);
+    /// assert_eq!(subsets.next(), Some(BitmapSet::only(Ordering::Less)));
+    /// assert_eq!(subsets.next(), Some(BitmapSet::only(Ordering::Greater)));
+    /// assert_eq!(subsets.next(), None);
+    /// ```
+    pub fn subsets_of_size(&self, k: usize) -> Combinations<T> {
+        Combinations {
+            set: self.0,
+            next: if k <= self.size() {
+                Some(lowest_ones(self.0, k))
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Iterates over all sets of values of `T`, in increasing order. This is equivalent to
+    /// iterating over [`BitmapSet`] as a [`Finite`] type, but works directly on the bitmap.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut sets = BitmapSet::<bool>::iter_all();
+    /// assert_eq!(sets.next(), Some(BitmapSet::none()));
+    /// assert_eq!(sets.next(), Some(BitmapSet::only(false)));
+    /// assert_eq!(sets.next(), Some(BitmapSet::only(true)));
+    /// assert_eq!(sets.next(), Some(BitmapSet::all()));
+    /// assert_eq!(sets.next(), None);
+    /// ```
+    pub fn iter_all() -> Subsets<T> {
+        Self::subsets_of(Self::all())
+    }
+
+    /// Iterates over all subsets of the given set, in increasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// use core::cmp::Ordering;
+    /// let set = BitmapSet::new(|x: Ordering| x != Ordering::Equal);
+    /// assert_eq!(BitmapSet::subsets_of(set).count(), 4);
+    /// assert!(BitmapSet::subsets_of(set).all(|subset| subset.is_subset(&set)));
+    /// ```
+    pub fn subsets_of(set: Self) -> Subsets<T> {
+        set.subsets()
+    }
+
+    /// Iterates over all subsets of this set, in increasing order. There are `2^n` of these, where
+    /// `n` is the size of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let set = BitmapSet::only(true);
+    /// assert!(set.subsets().eq([BitmapSet::none(), set]));
+    /// ```
+    pub fn subsets(&self) -> Subsets<T> {
+        Subsets {
+            set: self.0,
+            next: Some(T::Bitmap::ZERO),
+        }
+    }
 }
 
 impl<T: BitmapFinite> Default for BitmapSet<T> {
@@ -99,6 +517,131 @@ pub trait Set<T> {
 
     /// Ensures that the set excludes the given value.
     fn exclude(&mut self, value: T);
+
+    /// Adds the given value to the set, returning whether it was newly inserted.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut set = BitmapSet::none();
+    /// assert!(set.insert(true));
+    /// assert!(!set.insert(true));
+    /// ```
+    fn insert(&mut self, value: T) -> bool
+    where
+        T: Clone,
+    {
+        let res = !self.contains(value.clone());
+        self.include(value);
+        res
+    }
+
+    /// Removes the given value from the set, returning whether it was present.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    /// let mut set = BitmapSet::only(true);
+    /// assert!(!set.remove(false));
+    /// assert!(set.remove(true));
+    /// assert!(set.is_none());
+    /// ```
+    fn remove(&mut self, value: T) -> bool
+    where
+        T: Clone,
+    {
+        let res = self.contains(value.clone());
+        self.exclude(value);
+        res
+    }
+
+    /// Determines whether the set is empty.
+    ///
+    /// The default implementation checks every value of `T`.
+    fn is_empty(&self) -> bool
+    where
+        T: Finite,
+    {
+        !T::iter().any(|value| self.contains(value))
+    }
+
+    /// Gets the number of values in the set.
+    ///
+    /// The default implementation checks every value of `T`.
+    fn len(&self) -> usize
+    where
+        T: Finite,
+    {
+        T::iter()
+            .filter(|value| self.contains(value.clone()))
+            .count()
+    }
+
+    /// Removes all values from the set.
+    ///
+    /// The default implementation excludes every value of `T`.
+    fn clear(&mut self)
+    where
+        T: Finite,
+    {
+        T::iter().for_each(|value| self.exclude(value));
+    }
+
+    /// Adds all values in `other` to this set.
+    ///
+    /// The default implementation checks every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantor::*;
+    ///
+    /// fn fill<S: Set<bool>>(set: &mut S, other: &S) {
+    ///     set.union_with(other);
+    ///     assert!(!set.is_empty());
+    /// }
+    ///
+    /// let mut set = BitmapSet::only(false);
+    /// fill(&mut set, &BitmapSet::only(true));
+    /// assert_eq!(set, BitmapSet::all());
+    /// ```
+    fn union_with(&mut self, other: &Self)
+    where
+        T: Finite,
+    {
+        for value in T::iter() {
+            if other.contains(value.clone()) {
+                self.include(value);
+            }
+        }
+    }
+
+    /// Removes all values from this set which are not in `other`.
+    ///
+    /// The default implementation checks every value of `T`.
+    fn intersect_with(&mut self, other: &Self)
+    where
+        T: Finite,
+    {
+        for value in T::iter() {
+            if !other.contains(value.clone()) {
+                self.exclude(value);
+            }
+        }
+    }
+
+    /// Removes all values from this set which are in `other`.
+    ///
+    /// The default implementation checks every value of `T`.
+    fn difference_with(&mut self, other: &Self)
+    where
+        T: Finite,
+    {
+        for value in T::iter() {
+            if other.contains(value.clone()) {
+                self.exclude(value);
+            }
+        }
+    }
 }
 
 impl<T: BitmapFinite> Set<T> for BitmapSet<T> {
@@ -113,6 +656,44 @@ impl<T: BitmapFinite> Set<T> for BitmapSet<T> {
     fn exclude(&mut self, value: T) {
         self.0 = self.0 & !T::Bitmap::one_at(T::index_of(value));
     }
+
+    fn insert(&mut self, value: T) -> bool {
+        let bit = T::Bitmap::one_at(T::index_of(value));
+        let res = self.0 & bit == T::Bitmap::ZERO;
+        self.0 = self.0 | bit;
+        res
+    }
+
+    fn remove(&mut self, value: T) -> bool {
+        let bit = T::Bitmap::one_at(T::index_of(value));
+        let res = self.0 & bit != T::Bitmap::ZERO;
+        self.0 = self.0 & !bit;
+        res
+    }
+
+    fn is_empty(&self) -> bool {
+        BitmapSet::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        BitmapSet::len(self)
+    }
+
+    fn clear(&mut self) {
+        BitmapSet::clear(self)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        BitmapSet::union_with(self, other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        BitmapSet::intersect_with(self, other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        BitmapSet::difference_with(self, other)
+    }
 }
 
 impl<T: CompressFinite + BitmapFinite> Set<Compress<T>> for BitmapSet<T> {
@@ -127,28 +708,56 @@ impl<T: CompressFinite + BitmapFinite> Set<Compress<T>> for BitmapSet<T> {
     fn exclude(&mut self, value: Compress<T>) {
         self.0 = self.0 & !T::Bitmap::one_at(Compress::index_of(value));
     }
+
+    fn insert(&mut self, value: Compress<T>) -> bool {
+        let bit = T::Bitmap::one_at(Compress::index_of(value));
+        let res = self.0 & bit == T::Bitmap::ZERO;
+        self.0 = self.0 | bit;
+        res
+    }
+
+    fn remove(&mut self, value: Compress<T>) -> bool {
+        let bit = T::Bitmap::one_at(Compress::index_of(value));
+        let res = self.0 & bit != T::Bitmap::ZERO;
+        self.0 = self.0 & !bit;
+        res
+    }
+
+    fn is_empty(&self) -> bool {
+        BitmapSet::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        BitmapSet::len(self)
+    }
+
+    fn clear(&mut self) {
+        BitmapSet::clear(self)
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        BitmapSet::union_with(self, other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        BitmapSet::intersect_with(self, other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        BitmapSet::difference_with(self, other)
+    }
 }
 
 impl<T: BitmapFinite> Iterator for BitmapSet<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(index) = self.0.first_one() {
-            self.0 = self.0 & !T::Bitmap::one_at(index);
-            Some(unsafe { T::nth(index).unwrap_unchecked() })
-        } else {
-            None
-        }
+        self.pop_first()
     }
 }
 
 impl<T: BitmapFinite> DoubleEndedIterator for BitmapSet<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(index) = self.0.last_one() {
-            self.0 = self.0 & !T::Bitmap::one_at(index);
-            Some(unsafe { T::nth(index).unwrap_unchecked() })
-        } else {
-            None
-        }
+        self.pop_last()
     }
 }
 
@@ -220,6 +829,12 @@ impl<T: BitmapFinite> PartialEq for BitmapSet<T> {
 
 impl<T: BitmapFinite> Eq for BitmapSet<T> {}
 
+impl<T: BitmapFinite> core::hash::Hash for BitmapSet<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
 impl<T: BitmapFinite> PartialOrd for BitmapSet<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -232,8 +847,25 @@ impl<T: BitmapFinite> Ord for BitmapSet<T> {
     }
 }
 
+/// Sets are themselves [`Finite`], as long as the number of possible sets fits in a `usize`. Using
+/// this implementation for a type with too many values is a compile-time error:
+///
+/// ```compile_fail
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Big(bool, bool, bool, bool, bool, bool, bool);
+///
+/// let _ = BitmapSet::<Big>::COUNT;
+/// ```
 unsafe impl<T: BitmapFinite> Finite for BitmapSet<T> {
-    const COUNT: usize = 1 << T::COUNT;
+    const COUNT: usize = {
+        assert!(
+            T::COUNT < usize::BITS as usize,
+            "too many values for BitmapSet to implement Finite"
+        );
+        1 << T::COUNT
+    };
 
     fn index_of(value: Self) -> usize {
         value.0.to_usize()
@@ -254,6 +886,59 @@ impl<T: core::fmt::Debug + BitmapFinite> core::fmt::Debug for BitmapSet<T> {
     }
 }
 
+/// Formats the set as a comma-separated list of its values, enclosed in braces.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let set = BitmapSet::new(|x: u8| x % 100 == 1);
+/// assert_eq!(set.to_string(), "{1, 101, 201}");
+/// assert_eq!(format!("{:#x}", set), "0x200000000000000000000000020000000000000000000000002");
+/// ```
+impl<T: core::fmt::Display + BitmapFinite> core::fmt::Display for BitmapSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("{")?;
+        for (i, value) in self.enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            value.fmt(f)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// Formats the underlying bitmap of the set, as given by [`BitmapSet::to_bits`].
+impl<T: BitmapFinite> core::fmt::Binary for BitmapSet<T>
+where
+    T::Bitmap: core::fmt::Binary,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Formats the underlying bitmap of the set, as given by [`BitmapSet::to_bits`].
+impl<T: BitmapFinite> core::fmt::LowerHex for BitmapSet<T>
+where
+    T::Bitmap: core::fmt::LowerHex,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[test]
+fn test_rank_select() {
+    let set = BitmapSet::new(|x| x != Digit::Positive(false, true));
+    for (n, value) in set.enumerate() {
+        assert_eq!(set.rank(value), n);
+        assert_eq!(set.select(n), Some(value));
+    }
+    assert_eq!(set.select(3), Some(Digit::Positive(true, true)));
+    assert_eq!(set.select(4), None);
+}
+
 #[test]
 fn test_debug() {
     extern crate alloc;
@@ -262,3 +947,273 @@ fn test_debug() {
     set.include(true);
     assert_eq!(alloc::format!("{:?}", set), "{false, true}");
 }
+
+/// Gets the union of the given sets, or the empty set if there are none.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let set = union_all([BitmapSet::only(false), BitmapSet::only(true)]);
+/// assert_eq!(set, BitmapSet::all());
+/// ```
+pub fn union_all<T: BitmapFinite>(sets: impl IntoIterator<Item = BitmapSet<T>>) -> BitmapSet<T> {
+    sets.into_iter().fold(BitmapSet::none(), |a, b| a | b)
+}
+
+/// Gets the intersection of the given sets, or the set of all values if there are none.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let set = intersection_all([BitmapSet::all(), BitmapSet::only(true)]);
+/// assert_eq!(set, BitmapSet::only(true));
+/// ```
+pub fn intersection_all<T: BitmapFinite>(
+    sets: impl IntoIterator<Item = BitmapSet<T>>,
+) -> BitmapSet<T> {
+    sets.into_iter().fold(BitmapSet::all(), |a, b| a & b)
+}
+
+/// An iterator adaptor which filters out values that have already been produced by the
+/// underlying iterator. See [`unique`].
+pub struct Unique<I: Iterator>
+where
+    I::Item: BitmapFinite,
+{
+    source: I,
+    seen: BitmapSet<I::Item>,
+}
+
+/// Filters out repeated values from the given iterator, keeping only the first occurrence of each
+/// value. Unlike a hash-based approach, this tracks seen values using a [`BitmapSet`], so it never
+/// allocates.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let values: Vec<bool> = unique([true, true, false, true]).collect();
+/// assert_eq!(values, [true, false]);
+/// ```
+pub fn unique<I: IntoIterator>(iter: I) -> Unique<I::IntoIter>
+where
+    I::Item: BitmapFinite,
+{
+    Unique {
+        source: iter.into_iter(),
+        seen: BitmapSet::none(),
+    }
+}
+
+impl<I: Iterator> Iterator for Unique<I>
+where
+    I::Item: BitmapFinite,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let seen = &mut self.seen;
+        self.source.find(|value| seen.insert(value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.source.size_hint();
+        let rem = I::Item::COUNT - self.seen.size();
+        (0, Some(upper.map_or(rem, |upper| upper.min(rem))))
+    }
+}
+
+/// An iterator over all [`BitmapSet`]s of a particular size, as returned by
+/// [`BitmapSet::combinations`] and [`BitmapSet::subsets_of_size`].
+pub struct Combinations<T: BitmapFinite> {
+    set: T::Bitmap,
+    next: Option<T::Bitmap>,
+}
+
+impl<T: BitmapFinite> Iterator for Combinations<T> {
+    type Item = BitmapSet<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let bitmap = self.next?;
+
+        // Move the lowest run of ones (considering only bits in the set) up by one position by
+        // setting the next unset bit in the set above it, then move all but one of the ones in the
+        // run down to the bottom
+        self.next = bitmap.first_one().and_then(|low| {
+            let high = (self.set & !bitmap & !T::Bitmap::ones(low)).first_one()?;
+            let run = T::Bitmap::count_ones(bitmap & T::Bitmap::ones(high));
+            Some(
+                (bitmap & !T::Bitmap::ones(high))
+                    | T::Bitmap::one_at(high)
+                    | lowest_ones(self.set, run - 1),
+            )
+        });
+        Some(BitmapSet(bitmap))
+    }
+}
+
+/// Gets the bitmap consisting of the lowest `n` set bits of the given bitmap, which must have at
+/// least `n` set bits.
+fn lowest_ones<B: Unsigned>(mut bitmap: B, n: usize) -> B {
+    let mut res = B::ZERO;
+    for _ in 0..n {
+        let index = bitmap.first_one().unwrap();
+        bitmap = bitmap & !B::one_at(index);
+        res = res | B::one_at(index);
+    }
+    res
+}
+
+impl<T: BitmapFinite> core::iter::FusedIterator for Combinations<T> {}
+
+/// An iterator over all subsets of a [`BitmapSet`], as returned by [`BitmapSet::subsets`].
+pub struct Subsets<T: BitmapFinite> {
+    set: T::Bitmap,
+    next: Option<T::Bitmap>,
+}
+
+impl<T: BitmapFinite> Iterator for Subsets<T> {
+    type Item = BitmapSet<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let bitmap = self.next?;
+
+        // Treating the bits of the set as the digits of a counter, increment by setting the
+        // lowest unset digit and clearing all digits below it
+        self.next = (self.set & !bitmap)
+            .first_one()
+            .map(|low| (bitmap & !T::Bitmap::ones(low)) | T::Bitmap::one_at(low));
+        Some(BitmapSet(bitmap))
+    }
+}
+
+impl<T: BitmapFinite> core::iter::FusedIterator for Subsets<T> {}
+
+#[cfg(test)]
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Digit {
+    Zero,
+    Positive(bool, bool),
+}
+
+#[test]
+fn test_unique() {
+    let mut iter = unique([
+        Digit::Positive(true, false),
+        Digit::Zero,
+        Digit::Positive(true, false),
+        Digit::Positive(false, true),
+        Digit::Zero,
+    ]);
+    assert_eq!(iter.next(), Some(Digit::Positive(true, false)));
+    assert_eq!(iter.next(), Some(Digit::Zero));
+    assert_eq!(iter.next(), Some(Digit::Positive(false, true)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_combinations() {
+    let mut prev = None;
+    let mut count = 0;
+    for set in BitmapSet::<Digit>::combinations(2) {
+        assert_eq!(set.size(), 2);
+        assert!(prev < Some(set));
+        prev = Some(set);
+        count += 1;
+    }
+    assert_eq!(count, 10);
+    assert!(BitmapSet::<Digit>::combinations(0).eq([BitmapSet::none()]));
+    assert!(BitmapSet::<Digit>::combinations(5).eq([BitmapSet::all()]));
+    assert_eq!(BitmapSet::<Digit>::combinations(6).count(), 0);
+    let set = BitmapSet::new(|x: Digit| x != Digit::Positive(false, true));
+    let mut count = 0;
+    for subset in set.subsets_of_size(3) {
+        assert_eq!(subset.size(), 3);
+        assert!(subset.is_subset(&set));
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert!(set.subsets_of_size(4).eq([set]));
+    assert_eq!(set.subsets_of_size(5).count(), 0);
+    assert_eq!(BitmapSet::<bool>::combinations(1).count(), 2);
+}
+
+#[test]
+fn test_subsets() {
+    assert!(BitmapSet::<Digit>::iter_all().eq(BitmapSet::<Digit>::iter()));
+    let set = BitmapSet::new(|x| matches!(x, Digit::Positive(true, _) | Digit::Zero));
+    let mut prev = None;
+    let mut count = 0;
+    for subset in set.subsets() {
+        assert!(subset.is_subset(&set) && set.is_superset(&subset));
+        assert!(subset.is_disjoint(&(BitmapSet::all() - set)));
+        assert!(prev < Some(subset));
+        prev = Some(subset);
+        count += 1;
+    }
+    assert_eq!(count, 8);
+    assert!(BitmapSet::<Digit>::subsets_of(BitmapSet::none()).eq([BitmapSet::none()]));
+}
+
+#[test]
+fn test_retain() {
+    let mut set = BitmapSet::new(|x| x != Digit::Positive(false, true));
+    let mut visited = BitmapSet::none();
+    set.retain(|x| {
+        assert!(visited.insert(x));
+        matches!(x, Digit::Positive(_, true))
+    });
+    assert_eq!(visited.size(), 4);
+    assert_eq!(set, BitmapSet::only(Digit::Positive(true, true)));
+}
+
+#[test]
+fn test_set_methods() {
+    let mut set = BitmapSet::new(|x| matches!(x, Digit::Positive(true, _)));
+    let other = BitmapSet::new(|x| matches!(x, Digit::Positive(_, true)));
+    assert_eq!(set.len(), 2);
+    set.union_with(&other);
+    assert_eq!(set.len(), 3);
+    set.difference_with(&BitmapSet::only(Digit::Positive(true, true)));
+    set.intersect_with(&other);
+    assert_eq!(set, BitmapSet::only(Digit::Positive(false, true)));
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_first_last() {
+    let mut set = BitmapSet::new(|x| matches!(x, Digit::Positive(_, true)));
+    assert_eq!(set.first(), Some(Digit::Positive(false, true)));
+    assert_eq!(set.last(), Some(Digit::Positive(true, true)));
+    assert_eq!(set.pop_last(), Some(Digit::Positive(true, true)));
+    assert_eq!(set.pop_first(), Some(Digit::Positive(false, true)));
+    assert_eq!(set.first(), None);
+    assert_eq!(set.pop_last(), None);
+    assert_eq!(<BitmapSet<Digit> as Finite>::last(), Some(BitmapSet::all()));
+}
+
+#[test]
+fn test_union_intersection_all() {
+    let sets =
+        [Digit::Zero, Digit::Positive(true, false)].map(|x| BitmapSet::new(|y: Digit| y != x));
+    assert_eq!(union_all(sets), BitmapSet::all());
+    assert_eq!(intersection_all(sets).size(), 3);
+    assert_eq!(union_all::<Digit>([]), BitmapSet::none());
+    assert_eq!(intersection_all::<Digit>([]), BitmapSet::all());
+}
+
+#[test]
+fn test_map() {
+    let set = BitmapSet::new(|x| x != Digit::Positive(false, true));
+    let mut visited = BitmapSet::none();
+    let mapped = set.map_set(|x| {
+        assert!(visited.insert(x));
+        matches!(x, Digit::Positive(true, _))
+    });
+    assert_eq!(visited, set);
+    assert_eq!(mapped, BitmapSet::all());
+    let mapped = set.filter_map_set(|x| match x {
+        Digit::Zero => None,
+        Digit::Positive(a, b) => Some(a && b),
+    });
+    assert_eq!(mapped, BitmapSet::all());
+    assert_eq!(set.filter_map_set(|_| None::<bool>), BitmapSet::none());
+    assert!(set.map(Digit::index_of).eq([0, 1, 3, 4]));
+}
@@ -0,0 +1,135 @@
+use crate::*;
+use core::marker::PhantomData;
+
+/// Iterates over all of the values of a [`Finite`] type in a pseudo-random order determined by
+/// the given seed. Each value is visited exactly once, and no allocation is required.
+///
+/// This uses a Feistel network to permute the index space, which makes it suitable for ordering
+/// exhaustive tests, but not for cryptographic purposes.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let mut values = shuffled::<u8>(42).collect::<Vec<_>>();
+/// assert_ne!(values, (0..=255).collect::<Vec<_>>());
+/// values.sort();
+/// assert_eq!(values, (0..=255).collect::<Vec<_>>());
+/// ```
+pub fn shuffled<T: Finite>(seed: u64) -> Shuffled<T> {
+    // The domain of the permutation has an even number of bits, and at most 64
+    let bits = usize::BITS - T::COUNT.saturating_sub(1).leading_zeros();
+    let half_bits = bits.div_ceil(2);
+    let mut state = seed;
+    let keys = [(); ROUNDS].map(|_| {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        mix(state)
+    });
+    Shuffled {
+        counter: 0,
+        remaining: T::COUNT,
+        half_bits,
+        keys,
+        marker: PhantomData,
+    }
+}
+
+/// The number of rounds used for the Feistel network in [`Shuffled`].
+const ROUNDS: usize = 4;
+
+/// An iterator over all of the values of a [`Finite`] type in a pseudo-random order, as returned
+/// by [`shuffled`].
+pub struct Shuffled<T: Finite> {
+    counter: u128,
+    remaining: usize,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Finite> Shuffled<T> {
+    /// Applies the permutation for this iterator to an index in `0..(1 << (2 * half_bits))`.
+    fn permute(&self, index: u64) -> u64 {
+        let mask = (1u64 << self.half_bits) - 1;
+        let mut left = index >> self.half_bits;
+        let mut right = index & mask;
+        for key in self.keys {
+            let next_right = left ^ (mix(right ^ key) & mask);
+            left = right;
+            right = next_right;
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+impl<T: Finite> Iterator for Shuffled<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // Since the permutation is over a domain which may be larger than `T::COUNT`, skip over
+        // indices outside the range (i.e. "cycle walking"). At most 3/4 of the domain is out of
+        // range, so this is fast on average.
+        loop {
+            // The domain has at most `2^64` indices, and iteration stops after the last index in
+            // range, so `counter` fits in a `u64` whenever it is used
+            let index = self.permute(self.counter as u64);
+            self.counter += 1;
+            if index < T::COUNT as u64 {
+                self.remaining -= 1;
+                return Some(unsafe { T::nth_unchecked(index as usize) });
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Finite> ExactSizeIterator for Shuffled<T> {}
+
+impl<T: Finite> core::iter::FusedIterator for Shuffled<T> {}
+
+/// A bijective integer mixing function (the finalizer from SplitMix64).
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+#[test]
+fn test_shuffled() {
+    let mut seen = [false; 1 << 16];
+    let mut num_fixed = 0;
+    for (i, value) in shuffled::<u16>(7).enumerate() {
+        assert!(!seen[value as usize]);
+        seen[value as usize] = true;
+        num_fixed += (i == value as usize) as usize;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+    assert!(num_fixed < 100);
+    assert!(!shuffled::<u16>(1).eq(shuffled::<u16>(2)));
+    assert_eq!(shuffled::<bool>(0).len(), 2);
+    assert_eq!(shuffled::<()>(0).count(), 1);
+    assert_eq!(shuffled::<core::convert::Infallible>(0).count(), 0);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_shuffled_large() {
+    finite_range!(struct R65(0..=64): u8);
+
+    #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+    struct Big(u16, u16, u16, u8, R65);
+    assert_eq!(Big::COUNT, 65 << 56);
+    let mut values = shuffled::<Big>(1);
+    let (a, b) = (values.next().unwrap(), values.next().unwrap());
+    assert_ne!(a, b);
+    assert_eq!(values.len(), Big::COUNT - 2);
+
+    finite_range!(struct Huge(0..=18446744073709551614): u64);
+    assert_eq!(Huge::COUNT, usize::MAX);
+    assert!(shuffled::<Huge>(1).next().is_some());
+}
@@ -0,0 +1,42 @@
+use crate::*;
+use core::iter::Step;
+
+/// A wrapper over a [`Finite`] value which implements [`Step`], so that ranges of values can be
+/// iterated over directly. Requires the `nightly` feature.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+/// let values = (Stepped(3u8)..=Stepped(5u8)).map(|Stepped(value)| value);
+/// assert!(values.eq([3, 4, 5]));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Stepped<T: Finite>(pub T);
+
+impl<T: Finite> Step for Stepped<T> {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        let start = T::index_of(start.0.clone());
+        let end = T::index_of(end.0.clone());
+        match end.checked_sub(start) {
+            Some(steps) => (steps, Some(steps)),
+            None => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        T::nth(T::index_of(start.0).checked_add(count)?).map(Stepped)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        T::nth(T::index_of(start.0).checked_sub(count)?).map(Stepped)
+    }
+}
+
+#[test]
+fn test_stepped() {
+    let mut range = Stepped(i8::MIN)..Stepped(i8::MAX);
+    assert_eq!(range.size_hint(), (255, Some(255)));
+    assert_eq!(range.next_back(), Some(Stepped(i8::MAX - 1)));
+    assert!((Stepped(true)..Stepped(false)).next().is_none());
+    assert_eq!((Stepped(false)..=Stepped(true)).count(), 2);
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use core::ops::Bound;
 
 /// Ensures that the integer mapping of the given [`Finite`] is a valid bijection of the given
 /// size.
@@ -7,14 +8,41 @@ fn validate<F: Finite>(expected: usize) {
     assert_eq!(expected, F::COUNT);
     for i in 0..F::COUNT {
         assert_eq!(i, F::index_of(F::nth(i).unwrap()));
+        assert!(F::nth(i) == Some(unsafe { F::nth_unchecked(i) }));
     }
-    for i in 0..(F::COUNT - 1) {
+    for i in 0..F::COUNT.saturating_sub(1) {
         assert!(F::nth(i).unwrap() < F::nth(i + 1).unwrap());
     }
     assert!(F::nth(expected + 1).is_none());
 }
 
-#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[test]
+fn test_signed() {
+    validate::<i8>(1 << 8);
+    validate::<i16>(1 << 16);
+    assert_eq!(i8::index_of(i8::MIN), 0);
+    assert_eq!(i8::index_of(0), 128);
+    assert_eq!(i16::nth(0), Some(i16::MIN));
+}
+
+#[test]
+fn test_ordering() {
+    use core::cmp::Ordering;
+    validate::<Ordering>(3);
+    let map = ArrayMap::new(|o: Ordering| o.reverse());
+    assert_eq!(map[Ordering::Less], Ordering::Greater);
+}
+
+#[test]
+#[cfg(feature = "large-primitives")]
+fn test_u32() {
+    assert_eq!(u32::COUNT, 1 << 32);
+    assert_eq!(u32::nth(u32::MAX as usize), Some(u32::MAX));
+    assert_eq!(u32::nth(1 << 32), None);
+    assert_eq!(compress(123456789u32).expand(), 123456789);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 enum Color {
     Red,
     Green,
@@ -26,6 +54,16 @@ fn test_color() {
     validate::<Color>(3);
     validate::<Option<Color>>(4);
     validate::<(Color, Color)>(9);
+    validate::<Result<Color, bool>>(5);
+    validate::<(Color, bool, Color)>(18);
+    validate::<core::cmp::Reverse<Color>>(3);
+    validate::<core::task::Poll<Color>>(4);
+    #[cfg(feature = "either")]
+    validate::<either::Either<bool, Color>>(5);
+    validate::<(PhantomData<str>, Color)>(3);
+    validate::<(bool, Color, bool, bool, bool, bool, bool, bool, bool, bool, bool, Color)>(
+        9 << 10,
+    );
 }
 
 #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -99,4 +137,530 @@ struct Options {
 #[test]
 fn test_options() {
     validate::<Options>(3 * (2 + 2));
-}
\ No newline at end of file
+}
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[finite(binary_search)]
+enum SearchTile {
+    Empty,
+    Horizontal(Color),
+    Vertical(Color),
+    Cross {
+        horizontal: Color,
+        vertical: Color,
+        is_horizontal_above: bool
+    },
+    Blocked
+}
+
+#[test]
+fn test_binary_search() {
+    validate::<SearchTile>(1 + 3 + 3 + 3 * 3 * 2 + 1);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Mixed {
+    A,
+    B,
+    C(Color),
+    D,
+    E,
+    F
+}
+
+#[test]
+fn test_mixed() {
+    validate::<Mixed>(2 + 3 + 3);
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Empty {}
+
+unsafe impl Finite for Empty {
+    const COUNT: usize = 0;
+
+    fn index_of(value: Self) -> usize {
+        match value {}
+    }
+
+    fn nth(_: usize) -> Option<Self> {
+        None
+    }
+}
+
+impl_concrete_finite!(Empty);
+
+#[test]
+fn test_empty() {
+    validate::<Empty>(0);
+    validate::<Option<Empty>>(1);
+    assert_eq!(Empty::iter().next(), None);
+    let set = BitmapSet::<Empty>::all();
+    assert!(set.is_none());
+    assert_eq!(set, BitmapSet::none());
+    assert_eq!(BitmapSet::<Empty>::COUNT, 1);
+    let map = ArrayMap::<Empty, u32>::new(|x| match x {});
+    assert!(map == ArrayMap::default());
+    assert_eq!(core::mem::size_of::<Compress<Empty>>(), 0);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+struct Triple(bool, bool, bool);
+
+#[test]
+fn test_full_bitmap() {
+    let set = BitmapSet::<Triple>::all();
+    assert_eq!(set.size(), 8);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Never {}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Partial {
+    A(core::convert::Infallible),
+    B,
+    C(Color, Never),
+    D(Color)
+}
+
+#[test]
+fn test_uninhabited() {
+    use core::convert::Infallible;
+    validate::<Infallible>(0);
+    validate::<Never>(0);
+    validate::<(Color, Never)>(0);
+    validate::<Result<Infallible, Color>>(3);
+    validate::<Partial>(1 + 3);
+    assert!(BitmapSet::<Infallible>::all().is_none());
+    assert_eq!(core::mem::size_of::<ArrayMap<Never, u64>>(), 0);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[finite(concrete = "Pair<bool>", concrete = "Pair<Color>", convert, self_test)]
+struct Pair<T>(T, T);
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Either<A, B> {
+    Neither,
+    Left(A),
+    Middle,
+    Both { left: A, right: B },
+    Right(B),
+    First,
+    Second
+}
+
+#[test]
+fn test_generic() {
+    validate::<Pair<Color>>(3 * 3);
+    validate::<Either<Color, bool>>(1 + 3 + 1 + 3 * 2 + 2 + 2);
+    validate::<Either<Never, Pair<bool>>>(1 + 1 + 4 + 2);
+}
+
+#[test]
+fn test_generic_concrete() {
+    let set = BitmapSet::new(|Pair(a, b): Pair<bool>| a != b);
+    assert_eq!(set.size(), 2);
+    let map = ArrayMap::new(|Pair(a, b): Pair<Color>| a == b);
+    assert!(map[Pair(Color::Green, Color::Green)] && !map[Pair(Color::Red, Color::Green)]);
+    assert!(compress(Pair(Color::Red, Color::Blue)).expand() == Pair(Color::Red, Color::Blue));
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Legacy {
+    A,
+    #[finite(skip)]
+    Old(u64),
+    B,
+    C(bool),
+}
+
+#[test]
+fn test_skip() {
+    validate::<Legacy>(4);
+    assert_eq!(Legacy::index_of(Legacy::B), 1);
+    assert!(Legacy::iter().all(|value| !matches!(value, Legacy::Old(_))));
+}
+
+#[test]
+#[should_panic]
+fn test_skip_index_of() {
+    Legacy::index_of(Legacy::Old(0));
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Pinned {
+    #[finite(index = 0)]
+    A,
+    B(bool),
+    #[finite(index = 3)]
+    C(Color),
+    #[finite(index = 6)]
+    D,
+}
+
+#[test]
+fn test_pinned_index() {
+    validate::<Pinned>(7);
+    assert_eq!(Pinned::index_of(Pinned::C(Color::Red)), 3);
+    assert_eq!(Pinned::index_of(Pinned::D), 6);
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[finite(table, const_fn, self_test, names)]
+enum Opcode {
+    Nop,
+    Load,
+    Store,
+    #[finite(skip)]
+    Debug,
+    Add,
+    Jump,
+}
+
+#[test]
+fn test_table() {
+    validate::<Opcode>(5);
+    assert!(Opcode::nth(3) == Some(Opcode::Add));
+    assert!(Opcode::iter().all(|op| op != Opcode::Debug));
+}
+
+#[test]
+fn test_const_fn() {
+    const NUM_LOADS: usize = Opcode::index_of_const(Opcode::Store);
+    let table = [0u8; NUM_LOADS];
+    assert_eq!(table.len(), 2);
+    for op in Opcode::iter() {
+        assert_eq!(op.index_of_const(), Opcode::index_of(op));
+        assert!(Opcode::nth_const(op.index_of_const()) == Some(op));
+    }
+    assert!(Opcode::nth_const(5).is_none());
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[finite(binary_search)]
+enum NeverSearch {}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[finite(table, const_fn)]
+enum NeverTable {}
+
+#[test]
+fn test_empty_enum() {
+    validate::<NeverSearch>(0);
+    validate::<NeverTable>(0);
+    assert!(Never::nth(0).is_none());
+    assert!(NeverSearch::nth(usize::MAX).is_none());
+    assert!(NeverTable::nth_const(0).is_none());
+    assert_eq!(Never::iter().count(), 0);
+    assert!(BitmapSet::<Never>::all().is_none());
+}
+
+#[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+#[repr(u8)]
+#[finite(convert, index_type = "u16")]
+enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[test]
+fn test_repr() {
+    validate::<Register>(4);
+    assert_eq!(Register::nth(2), Some(Register::C));
+    assert_eq!(Register::index_of(Register::D), 3);
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(Register::try_from(1), Ok(Register::B));
+    assert_eq!(Register::try_from(4), Err(TryFromIndexError::new(4, 4)));
+    assert_eq!(usize::from(Register::C), 2);
+    assert!(Pair::<bool>::try_from(3).ok() == Some(Pair(true, true)));
+    assert_eq!(usize::from(Pair(Color::Green, Color::Red)), 3);
+}
+
+#[test]
+fn test_generic_iter() {
+    fn num_distinct<T: Finite>() -> usize {
+        assert!(T::iter().eq((0..T::COUNT).map(|index| T::nth(index).unwrap())));
+        assert!(T::iter().zip(T::iter().skip(1)).all(|(a, b)| a < b));
+        T::iter().count()
+    }
+    assert_eq!(num_distinct::<Pair<Color>>(), 9);
+    assert_eq!(num_distinct::<Either<bool, Never>>(), 1 + 2 + 1 + 2);
+    assert!(Pair::<bool>::iter().eq([
+        Pair(false, false),
+        Pair(false, true),
+        Pair(true, false),
+        Pair(true, true)
+    ]));
+}
+
+finite_range!(struct Dice(1..=6): u8);
+finite_range!(struct Offset(-3..=3): i32);
+finite_range!(
+    struct High(
+        340282366920938463463374607431768211400..=340282366920938463463374607431768211455
+    ): u128
+);
+finite_range!(struct Tail(-9223372036854775808..=-9223372036854775800): i64);
+
+#[test]
+fn test_finite_range() {
+    validate::<Dice>(6);
+    validate::<Offset>(7);
+    validate::<High>(56);
+    validate::<Tail>(9);
+    assert_eq!(High::index_of(High::MAX), 55);
+    assert_eq!(Tail::nth(8), Some(Tail::MAX));
+    assert_eq!(Dice::new(0), None);
+    assert_eq!(Dice::MAX.get(), 6);
+    assert_eq!(Offset::nth(0), Some(Offset::MIN));
+    assert_eq!(Offset::index_of(Offset::new(0).unwrap()), 3);
+    let set = BitmapSet::new(|dice: Dice| dice.get() > 3);
+    assert_eq!(set.size(), 3);
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl_finite!(Suit = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+struct Unsorted(u8);
+
+impl_finite!(Unsorted = [Unsorted(3), Unsorted(1)]);
+
+#[test]
+fn test_impl_finite() {
+    validate::<Suit>(4);
+    assert_eq!(Suit::index_of(Suit::Diamonds), 1);
+    let map = ArrayMap::new(|suit: Suit| suit >= Suit::Hearts);
+    assert!(map[Suit::Spades] && !map[Suit::Clubs]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_impl_finite_unsorted() {
+    Unsorted::nth(0);
+}
+
+#[test]
+fn test_index_type() {
+    assert_eq!(core::mem::size_of::<Compress<Register>>(), 2);
+    assert_eq!(compress(Register::C).expand(), Register::C);
+    assert_eq!(core::mem::size_of::<Compress<Opcode>>(), 1);
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(Opcode::NAMES, ["Nop", "Load", "Store", "Add", "Jump"]);
+    assert_eq!(Opcode::Store.name(), "Store");
+    assert!("Add".parse::<Opcode>() == Ok(Opcode::Add));
+    assert!("Debug".parse::<Opcode>() == Err(ParseNameError));
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let mut iter = Color::iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(Color::Blue));
+    assert_eq!(iter.next(), Some(Color::Red));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(Color::Green));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert!(u16::iter().rev().take(2).eq([u16::MAX, u16::MAX - 1]));
+    assert_eq!(u16::iter().nth(1000), Some(1000));
+    assert_eq!(u16::iter().skip(60000).nth_back(5530), Some(60005));
+    assert_eq!(u16::iter().nth(usize::MAX), None);
+    assert_eq!(u16::iter().last(), Some(u16::MAX));
+    assert_eq!(u16::iter().skip(1).count(), 65535);
+}
+
+#[test]
+fn test_first_last() {
+    assert_eq!(Color::first(), Some(Color::Red));
+    assert_eq!(Color::last(), Some(Color::Blue));
+    assert_eq!(i16::first(), Some(i16::MIN));
+    assert_eq!(i16::last(), Some(i16::MAX));
+    assert!(Never::first().is_none());
+    assert!(Never::last().is_none());
+}
+
+#[test]
+fn test_range() {
+    assert!(Color::range(Color::Green..).eq([Color::Green, Color::Blue]));
+    assert!(Color::range(..Color::Green).eq([Color::Red]));
+    assert_eq!(Color::range(Color::Blue..Color::Red).count(), 0);
+    assert_eq!(i8::range(-3..=3).len(), 7);
+    assert!(i8::range((Bound::Excluded(-1), Bound::Included(1))).eq([0, 1]));
+    assert!(Pair::<bool>::range(Pair(false, true)..Pair(true, true)).eq([
+        Pair(false, true),
+        Pair(true, false)
+    ]));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par() {
+    use rayon::prelude::*;
+    let map = ArrayMap::par_new(|x: u8| x as u32 * 3);
+    assert!(map == ArrayMap::new(|x: u8| x as u32 * 3));
+    let set = BitmapSet::par_new(|Pair(a, b): Pair<Color>| a < b);
+    assert!(set == BitmapSet::new(|Pair(a, b): Pair<Color>| a < b));
+    assert_eq!(set.size(), 3);
+    assert_eq!(par_values::<i8>().filter(|x| *x < 0).count(), 128);
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+struct Broken(u8);
+
+unsafe impl Finite for Broken {
+    const COUNT: usize = 3;
+
+    fn index_of(value: Self) -> usize {
+        value.0.min(2) as usize
+    }
+
+    fn nth(index: usize) -> Option<Self> {
+        [Broken(0), Broken(5), Broken(2)].get(index).copied()
+    }
+}
+
+#[test]
+fn test_validate() {
+    assert_eq!(crate::validate::<Pair<Color>>(), Ok(()));
+    assert_eq!(crate::validate::<Never>(), Ok(()));
+    assert_eq!(
+        crate::validate::<Broken>(),
+        Err(ValidationError::WrongIndex { index: 1, actual: 2 })
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_hash() {
+    use std::collections::HashSet;
+    let sets: HashSet<_> = BitmapSet::<Pair<bool>>::iter_all().collect();
+    assert_eq!(sets.len(), 16);
+    assert!(sets.contains(&BitmapSet::only(Pair(true, false))));
+    let maps: HashSet<_> = BitmapSet::<Pair<bool>>::iter_all()
+        .map(|set| ArrayMap::<_, bool>::new(|x: Pair<bool>| set.contains(x)))
+        .collect();
+    assert_eq!(maps.len(), 16);
+    let values: HashSet<_> = Pair::<Color>::iter().map(compress).collect();
+    assert_eq!(values.len(), Pair::<Color>::COUNT);
+    assert!(values.contains(&compress(Pair(Color::Red, Color::Blue))));
+}
+
+#[test]
+fn test_large_bitmap() {
+    let mut set = BitmapSet::new(|x: u8| x % 3 == 0);
+    assert_eq!(set.size(), 86);
+    assert!(set.contains(255) && !set.contains(254));
+    assert_eq!(set.last(), Some(255));
+    set.exclude(0);
+    assert_eq!(set.first(), Some(3));
+    assert!(set.eq((3..=255u8).step_by(3)));
+
+    #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+    struct Cell(u8, bool);
+    let set = BitmapSet::new(|Cell(a, b)| a == 200 && b);
+    assert_eq!(set.first(), Some(Cell(200, true)));
+    assert_eq!(set.rank(Cell(255, false)), 1);
+    assert!(BitmapSet::only(Cell(3, false)) < set);
+}
+
+/// Checks the set algebra methods of the [`Set`] trait for a particular backend.
+fn check_set_algebra<S: Set<Color> + Default>() {
+    let build = |values: &[Color]| {
+        let mut set = S::default();
+        values.iter().for_each(|value| set.include(*value));
+        set
+    };
+    let mut set = build(&[Color::Red, Color::Green]);
+    assert_eq!(set.len(), 2);
+    set.union_with(&build(&[Color::Blue]));
+    assert_eq!(set.len(), 3);
+    set.intersect_with(&build(&[Color::Red, Color::Blue]));
+    assert!(set.contains(Color::Red) && !set.contains(Color::Green));
+    set.difference_with(&build(&[Color::Red]));
+    assert!(set.contains(Color::Blue) && set.len() == 1);
+    assert!(!set.is_empty());
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_set_algebra() {
+    #[derive(Default)]
+    struct ListSet(Option<Color>, Option<Color>, Option<Color>);
+
+    impl Set<Color> for ListSet {
+        fn contains(&self, value: Color) -> bool {
+            [self.0, self.1, self.2].contains(&Some(value))
+        }
+
+        fn include(&mut self, value: Color) {
+            *match value {
+                Color::Red => &mut self.0,
+                Color::Green => &mut self.1,
+                Color::Blue => &mut self.2,
+            } = Some(value);
+        }
+
+        fn exclude(&mut self, value: Color) {
+            *match value {
+                Color::Red => &mut self.0,
+                Color::Green => &mut self.1,
+                Color::Blue => &mut self.2,
+            } = None;
+        }
+    }
+
+    check_set_algebra::<ListSet>();
+    check_set_algebra::<BitmapSet<Color>>();
+    check_set_algebra::<ArraySet<Color>>();
+    check_set_algebra::<ArrayMap<Color, bool>>();
+    #[cfg(feature = "alloc")]
+    check_set_algebra::<VecSet<Color>>();
+}
+
+#[test]
+fn test_collection_macros() {
+    let set = bitmap_set![Color::Red, Color::Blue, Color::Red];
+    assert!(set.eq([Color::Red, Color::Blue]));
+    assert_eq!(bitmap_set![], BitmapSet::<Color>::none());
+    let map = array_map![Color::Red => 1, Color::Blue => 3, _ => 2];
+    assert!(Color::iter().map(|c| map[c]).eq([1, 2, 3]));
+    let map: ArrayMap<Color, u8> = array_map![_ => 5];
+    assert!(Color::iter().all(|c| map[c] == 5));
+}
+
+#[test]
+fn test_display() {
+    extern crate alloc;
+    use alloc::format;
+    use alloc::string::ToString;
+    assert_eq!(BitmapSet::<bool>::none().to_string(), "{}");
+    assert_eq!(BitmapSet::<bool>::all().to_string(), "{false, true}");
+    assert_eq!(format!("{:b}", BitmapSet::only(true)), "10");
+    assert_eq!(format!("{:#x}", BitmapSet::<u8>::only(4)), "0x10");
+    assert_eq!(format!("{:b}", BitmapSet::<()>::all()), "1");
+    assert_eq!(format!("{:b}", BitmapSet::<Empty>::all()), "0");
+    let map = ArrayMap::new(|x: u8| x / 2);
+    let text = map.to_string();
+    assert_eq!(text.lines().count(), 256);
+    assert!(text.starts_with("0   => 0\n1   => 0\n"));
+    assert!(text.ends_with("\n255 => 127"));
+}
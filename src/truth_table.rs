@@ -0,0 +1,289 @@
+use crate::uint::log2;
+use crate::*;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+/// A boolean function of a [`Finite`] input type `I`, stored as an [`ArrayMap`] from inputs to
+/// outputs.
+///
+/// For the purposes of normal form extraction and minimization, inputs are identified with the
+/// binary digits of their index (according to [`Finite::index_of`]). When the input is a product
+/// of `bool`s, these coincide with the components of the input. Indices which do not correspond
+/// to any input are treated as "don't care"s.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Input(bool, bool, bool);
+///
+/// // Majority function of three inputs
+/// let table = TruthTable::new(|Input(a, b, c)| (a as u8) + (b as u8) + (c as u8) >= 2);
+/// assert_eq!(table.dnf().len(), 4);
+/// let cover = table.minimize();
+/// assert_eq!(cover.len(), 3);
+/// assert!(cover.iter().all(|term| term.num_literals() == 2));
+/// ```
+pub struct TruthTable<I: ArrayFinite<bool>>(ArrayMap<I, bool>);
+
+/// A set of literals, each constraining one binary digit of an input index. Depending on context,
+/// this is interpreted either as a conjunction of literals (in disjunctive normal form) or as a
+/// disjunction of literals (in conjunctive normal form).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Term {
+    /// The binary digits which have a literal in this term.
+    pub mask: usize,
+
+    /// The values of the literals in this term. Only the bits in [`Term::mask`] are meaningful,
+    /// all others are zero.
+    pub value: usize,
+}
+
+impl Term {
+    /// The number of literals in this term.
+    pub fn num_literals(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Determines whether all of the literals in this term are satisfied by the given index.
+    pub fn matches_index(&self, index: usize) -> bool {
+        index & self.mask == self.value
+    }
+
+    /// Iterates over the literals in this term, each given as the position of a binary digit and
+    /// its expected value.
+    pub fn literals(&self) -> impl Iterator<Item = (usize, bool)> {
+        let Term { mask, value } = *self;
+        (0..usize::BITS as usize)
+            .filter(move |bit| mask & (1 << bit) != 0)
+            .map(move |bit| (bit, value & (1 << bit) != 0))
+    }
+}
+
+impl<I: ArrayFinite<bool>> TruthTable<I> {
+    /// Constructs a [`TruthTable`] by evaluating the given function on all inputs.
+    pub fn new(f: impl FnMut(I) -> bool) -> Self {
+        Self(ArrayMap::new(f))
+    }
+
+    /// Constructs a [`TruthTable`] from a map of inputs to outputs.
+    pub fn from_map(map: ArrayMap<I, bool>) -> Self {
+        Self(map)
+    }
+
+    /// Gets the map of inputs to outputs for this table.
+    pub fn into_map(self) -> ArrayMap<I, bool> {
+        self.0
+    }
+
+    /// The number of binary digits needed to represent an input index.
+    pub fn num_vars(&self) -> usize {
+        log2(I::COUNT.saturating_sub(1))
+    }
+
+    /// Evaluates the function for the given input.
+    pub fn eval(&self, input: I) -> bool {
+        self.0[input]
+    }
+
+    /// Gets the canonical disjunctive normal form of this function: a disjunction of one term
+    /// (a conjunction of literals) for every input where the function is `true`.
+    pub fn dnf(&self) -> Vec<Term> {
+        let mask = self.full_mask();
+        self.indices(true)
+            .map(|index| Term { mask, value: index })
+            .collect()
+    }
+
+    /// Gets the canonical conjunctive normal form of this function: a conjunction of one term
+    /// (a disjunction of literals) for every input where the function is `false`.
+    pub fn cnf(&self) -> Vec<Term> {
+        let mask = self.full_mask();
+        self.indices(false)
+            .map(|index| Term {
+                mask,
+                value: !index & mask,
+            })
+            .collect()
+    }
+
+    /// Gets a compact disjunctive normal form of this function using Quine–McCluskey
+    /// minimization. The result consists only of prime implicants and includes all essential
+    /// prime implicants, but is not guaranteed to be a minimum cover.
+    ///
+    /// The cost of this operation grows exponentially with [`TruthTable::num_vars`].
+    pub fn minimize(&self) -> Vec<Term> {
+        self.minimize_for(true)
+    }
+
+    /// Gets a compact conjunctive normal form of this function, obtained by minimizing its
+    /// complement. See [`TruthTable::minimize`].
+    pub fn minimize_cnf(&self) -> Vec<Term> {
+        self.minimize_for(false)
+            .into_iter()
+            .map(|term| Term {
+                mask: term.mask,
+                value: !term.value & term.mask,
+            })
+            .collect()
+    }
+
+    /// A mask containing all binary digits of an input index.
+    fn full_mask(&self) -> usize {
+        (1 << self.num_vars()) - 1
+    }
+
+    /// Iterates over the indices of the inputs for which the function has the given output.
+    fn indices(&self, output: bool) -> impl Iterator<Item = usize> + '_ {
+        (0..I::COUNT).filter(move |i| self.0[unsafe { I::nth_unchecked(*i) }] == output)
+    }
+
+    /// Gets a compact cover of the inputs for which the function has the given output.
+    fn minimize_for(&self, output: bool) -> Vec<Term> {
+        let mask = self.full_mask();
+        let targets: Vec<usize> = self.indices(output).collect();
+
+        // Find prime implicants
+        let mut primes = Vec::new();
+        let mut cur: BTreeSet<Term> = targets
+            .iter()
+            .copied()
+            .chain(I::COUNT..=mask)
+            .map(|index| Term { mask, value: index })
+            .collect();
+        while !cur.is_empty() {
+            let mut next = BTreeSet::new();
+            let mut combined = BTreeSet::new();
+            for a in cur.iter() {
+                for bit in (0..self.num_vars()).map(|bit| 1 << bit) {
+                    if a.mask & bit != 0 && a.value & bit == 0 {
+                        let b = Term {
+                            mask: a.mask,
+                            value: a.value | bit,
+                        };
+                        if cur.contains(&b) {
+                            next.insert(Term {
+                                mask: a.mask & !bit,
+                                value: a.value,
+                            });
+                            combined.insert(*a);
+                            combined.insert(b);
+                        }
+                    }
+                }
+            }
+            primes.extend(cur.difference(&combined).copied());
+            cur = next;
+        }
+
+        // Select a cover, starting with essential prime implicants
+        let mut uncovered = targets;
+        let mut cover = Vec::new();
+        loop {
+            let essential = uncovered.iter().find_map(|index| {
+                let mut covering = primes.iter().filter(|p| p.matches_index(*index));
+                let first = covering.next();
+                if covering.next().is_none() {
+                    first.copied()
+                } else {
+                    None
+                }
+            });
+            let chosen = match essential {
+                Some(chosen) => chosen,
+                None => match primes.iter().max_by_key(|p| {
+                    let covered = uncovered.iter().filter(|i| p.matches_index(**i)).count();
+                    (covered, core::cmp::Reverse(p.num_literals()))
+                }) {
+                    Some(chosen) if !uncovered.is_empty() => *chosen,
+                    _ => break,
+                },
+            };
+            uncovered.retain(|index| !chosen.matches_index(*index));
+            primes.retain(|p| *p != chosen);
+            cover.push(chosen);
+        }
+        cover
+    }
+}
+
+impl<I: ArrayFinite<bool>> Index<I> for TruthTable<I> {
+    type Output = bool;
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<I: ArrayFinite<bool>> IndexMut<I> for TruthTable<I> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<I: ArrayFinite<bool>> Clone for TruthTable<I>
+where
+    ArrayMap<I, bool>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[test]
+fn test_minimize() {
+    #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+    struct Input(bool, bool, bool, bool);
+
+    let f = |Input(a, b, c, d): Input| (a && !b) || (c && d) || (!b && !c && !d);
+    let table = TruthTable::new(f);
+    let check = |cover: &[Term], output: bool| {
+        for input in Input::iter() {
+            let index = Input::index_of(input);
+            let matched = cover.iter().any(|t| t.matches_index(index));
+            assert_eq!(matched, f(input) == output);
+        }
+    };
+    let dnf = table.minimize();
+    check(&dnf, true);
+    assert!(dnf.len() <= 3);
+    let cnf = table.minimize_cnf();
+    let complement: Vec<Term> = cnf
+        .iter()
+        .map(|t| Term {
+            mask: t.mask,
+            value: !t.value & t.mask,
+        })
+        .collect();
+    check(&complement, false);
+}
+
+#[test]
+fn test_minimize_dont_care() {
+    // With 3 values, index 3 is a "don't care", so each of `B` and `C` is covered by a single
+    // literal.
+    let table = TruthTable::new(|x: Ternary| x != Ternary::A);
+    let mut cover = table.minimize();
+    cover.sort();
+    assert_eq!(
+        cover,
+        [
+            Term {
+                mask: 0b01,
+                value: 0b01
+            },
+            Term {
+                mask: 0b10,
+                value: 0b10
+            }
+        ]
+    );
+
+    #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+    enum Ternary {
+        A,
+        B,
+        C,
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(missing_docs)]
 #![doc(hidden)]
+use core::hash::Hash;
+use core::marker::PhantomData;
 use core::ops::{BitAnd, BitOr, BitXor, Not};
 
 /// A compact, generic unsigned integer with at least the given number of bits.
@@ -9,18 +11,72 @@ pub type Uint<const N: usize> = <NumBits<'static, N> as HasUint>::Uint;
 ///
 /// This has a lifetime parameter in order to work around issues with
 /// [trivial constraints](https://github.com/rust-lang/rust/issues/48214).
-pub struct NumBits<'a, const N: usize>(&'a ());
+pub struct NumBits<'a, const N: usize>(PhantomData<&'a ()>);
 
 /// Defines the [`Uint`] backing type for a certain number of bits.
 pub trait HasUint {
     type Uint: Unsigned;
 }
 
+/// A compact, generic unsigned integer for use as a bitmap with `N` bits, where `N` has been
+/// clamped using [`bitmap_bits`]. This is a [`Uint`] if one is available, and otherwise a
+/// [`BitArray`] with `W` words, as given by [`bitmap_words`]. No type is available for bitmaps
+/// with more than [`MAX_BITMAP_BITS`] bits.
+pub type BitmapUint<const N: usize, const W: usize> =
+    <NumBitmapBits<'static, N, W> as HasBitmapUint>::Uint;
+
+/// A type that implements [`HasBitmapUint`] for all sizes.
+///
+/// This has a lifetime parameter in order to work around issues with
+/// [trivial constraints](https://github.com/rust-lang/rust/issues/48214).
+pub struct NumBitmapBits<'a, const N: usize, const W: usize>(PhantomData<&'a ()>);
+
+/// Defines the [`BitmapUint`] backing type for a certain number of bits.
+pub trait HasBitmapUint {
+    type Uint: Unsigned;
+}
+
+impl<'a, const N: usize, const W: usize> HasBitmapUint for NumBitmapBits<'a, N, W>
+where
+    NumBits<'a, N>: HasUint,
+{
+    type Uint = <NumBits<'a, N> as HasUint>::Uint;
+}
+
+impl<'a, const W: usize> HasBitmapUint for NumBitmapBits<'a, { MAX_UINT_BITS + 1 }, W> {
+    type Uint = BitArray<W>;
+}
+
+/// The largest number of bits supported by [`Uint`].
+const MAX_UINT_BITS: usize = 128;
+
+/// The largest number of bits supported by [`BitmapUint`]. Beyond this, a bitmap would be too
+/// large to reasonably pass around by value.
+pub const MAX_BITMAP_BITS: usize = 4096;
+
+/// Gets the `N` parameter of [`BitmapUint`] for a bitmap with the given number of bits. For sizes
+/// above [`MAX_BITMAP_BITS`], this gives a value for which [`HasBitmapUint`] is not implemented.
+pub const fn bitmap_bits(n: usize) -> usize {
+    if n <= MAX_UINT_BITS {
+        n
+    } else if n <= MAX_BITMAP_BITS {
+        MAX_UINT_BITS + 1
+    } else {
+        usize::MAX
+    }
+}
+
+/// Gets the `W` parameter of [`BitmapUint`] for a bitmap with the given number of bits.
+pub const fn bitmap_words(n: usize) -> usize {
+    n.div_ceil(64)
+}
+
 /// Encapsulates the required operations for unsigned integers required by this crate.
 pub trait Unsigned:
     Ord
     + Clone
     + Copy
+    + Hash
     + BitOr<Self, Output = Self>
     + BitAnd<Self, Output = Self>
     + BitXor<Self, Output = Self>
@@ -34,13 +90,50 @@ pub trait Unsigned:
     fn count_ones(self) -> usize;
     fn first_one(self) -> Option<usize>;
     fn last_one(self) -> Option<usize>;
+
+    /// Gets the position of the one bit which has exactly `n` one bits below it.
+    fn select_one(self, n: usize) -> Option<usize>;
+}
+
+/// Gets the position of the one bit in `word` which has exactly `n` one bits below it.
+pub fn select_u64(mut word: u64, mut n: usize) -> Option<usize> {
+    // Skip whole bytes using popcounts, then clear the remaining lower bits one at a time
+    let mut base = 0;
+    loop {
+        if word == 0 {
+            return None;
+        }
+        let count = (word & 0xff).count_ones() as usize;
+        if n < count {
+            break;
+        }
+        n -= count;
+        word >>= 8;
+        base += 8;
+    }
+    for _ in 0..n {
+        word &= word - 1;
+    }
+    Some(base + word.trailing_zeros() as usize)
 }
 
 /// A zero-sized type that implements [`Unsigned`].
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 #[allow(non_camel_case_types)]
 pub struct u0;
 
+impl core::fmt::Binary for u0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Binary::fmt(&0u8, f)
+    }
+}
+
+impl core::fmt::LowerHex for u0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&0u8, f)
+    }
+}
+
 impl BitOr<u0> for u0 {
     type Output = u0;
     fn bitor(self, _: u0) -> Self::Output {
@@ -99,6 +192,10 @@ impl Unsigned for u0 {
     fn last_one(self) -> Option<usize> {
         None
     }
+
+    fn select_one(self, _: usize) -> Option<usize> {
+        None
+    }
 }
 
 macro_rules! impl_unsigned {
@@ -115,7 +212,11 @@ macro_rules! impl_unsigned {
             }
 
             fn ones(n: usize) -> Self {
-                (1 << n) - 1
+                if n < Self::BITS as usize {
+                    (1 << n) - 1
+                } else {
+                    !0
+                }
             }
 
             fn one_at(i: usize) -> Self {
@@ -143,6 +244,23 @@ macro_rules! impl_unsigned {
                     None
                 }
             }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn select_one(self, mut n: usize) -> Option<usize> {
+                let mut rest = self;
+                let mut base = 0;
+                while rest != 0 {
+                    let word = rest as u64;
+                    let count = word.count_ones() as usize;
+                    if n < count {
+                        return Some(base + select_u64(word, n)?);
+                    }
+                    n -= count;
+                    rest = rest.checked_shr(64).unwrap_or(0);
+                    base += 64;
+                }
+                None
+            }
         }
     };
 }
@@ -153,6 +271,134 @@ impl_unsigned!(u32);
 impl_unsigned!(u64);
 impl_unsigned!(u128);
 
+/// An unsigned integer consisting of `W` 64-bit words, in little-endian order. This implements
+/// [`Unsigned`] for bit counts beyond those of the primitive integer types.
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct BitArray<const W: usize>([u64; W]);
+
+impl<const W: usize> PartialOrd for BitArray<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const W: usize> Ord for BitArray<W> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Compare numerically, starting from the most significant word
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<const W: usize> BitOr<BitArray<W>> for BitArray<W> {
+    type Output = BitArray<W>;
+    fn bitor(self, rhs: BitArray<W>) -> Self::Output {
+        BitArray(core::array::from_fn(|i| self.0[i] | rhs.0[i]))
+    }
+}
+
+impl<const W: usize> BitAnd<BitArray<W>> for BitArray<W> {
+    type Output = BitArray<W>;
+    fn bitand(self, rhs: BitArray<W>) -> Self::Output {
+        BitArray(core::array::from_fn(|i| self.0[i] & rhs.0[i]))
+    }
+}
+
+impl<const W: usize> BitXor<BitArray<W>> for BitArray<W> {
+    type Output = BitArray<W>;
+    fn bitxor(self, rhs: BitArray<W>) -> Self::Output {
+        BitArray(core::array::from_fn(|i| self.0[i] ^ rhs.0[i]))
+    }
+}
+
+impl<const W: usize> Not for BitArray<W> {
+    type Output = BitArray<W>;
+    fn not(self) -> Self::Output {
+        BitArray(self.0.map(|word| !word))
+    }
+}
+
+impl<const W: usize> BitArray<W> {
+    /// Formats this integer by formatting its most significant non-zero word normally, followed by
+    /// the remaining words padded with zeros using the given function.
+    fn fmt_words(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        fmt_word: fn(&u64, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+        fmt_padded: fn(u64, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let mut words = self.0.iter().rev().skip_while(|word| **word == 0);
+        fmt_word(words.next().unwrap_or(&0), f)?;
+        words.try_for_each(|word| fmt_padded(*word, f))
+    }
+}
+
+impl<const W: usize> core::fmt::Binary for BitArray<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_words(f, core::fmt::Binary::fmt, |word, f| {
+            write!(f, "{:064b}", word)
+        })
+    }
+}
+
+impl<const W: usize> core::fmt::LowerHex for BitArray<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_words(f, core::fmt::LowerHex::fmt, |word, f| {
+            write!(f, "{:016x}", word)
+        })
+    }
+}
+
+impl<const W: usize> Unsigned for BitArray<W> {
+    const ZERO: Self = BitArray([0; W]);
+
+    fn from_usize_unchecked(source: usize) -> Self {
+        let mut res = Self::ZERO;
+        res.0[0] = source as u64;
+        res
+    }
+
+    fn to_usize(self) -> usize {
+        self.0[0] as usize
+    }
+
+    fn ones(n: usize) -> Self {
+        BitArray(core::array::from_fn(|i| {
+            u64::ones(n.saturating_sub(i * 64))
+        }))
+    }
+
+    fn one_at(i: usize) -> Self {
+        let mut res = Self::ZERO;
+        res.0[i / 64] = 1 << (i % 64);
+        res
+    }
+
+    fn count_ones(self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn first_one(self) -> Option<usize> {
+        let (i, word) = self.0.iter().enumerate().find(|(_, word)| **word != 0)?;
+        Some(i * 64 + word.trailing_zeros() as usize)
+    }
+
+    fn last_one(self) -> Option<usize> {
+        let (i, word) = self.0.iter().enumerate().rfind(|(_, word)| **word != 0)?;
+        Some(i * 64 + (63 - word.leading_zeros() as usize))
+    }
+
+    fn select_one(self, mut n: usize) -> Option<usize> {
+        for (i, word) in self.0.iter().enumerate() {
+            let count = word.count_ones() as usize;
+            if n < count {
+                return Some(i * 64 + select_u64(*word, n)?);
+            }
+            n -= count;
+        }
+        None
+    }
+}
+
 /// Computes the log-base-2 of an integer, rounding up if necessary.
 pub const fn log2(n: usize) -> usize {
     // TODO: Replace once int_log is stablized (https://github.com/rust-lang/rust/issues/70887)
@@ -296,3 +542,45 @@ impl_uint_for!(125, u128);
 impl_uint_for!(126, u128);
 impl_uint_for!(127, u128);
 impl_uint_for!(128, u128);
+
+#[test]
+fn test_bit_array() {
+    type B = BitArray<3>;
+    let a = B::ones(100);
+    assert_eq!(a.count_ones(), 100);
+    assert_eq!(a.first_one(), Some(0));
+    assert_eq!(a.last_one(), Some(99));
+    assert_eq!((!a).first_one(), Some(100));
+    assert_eq!((!a).last_one(), Some(191));
+    assert_eq!(B::one_at(130).first_one(), Some(130));
+    assert!(B::one_at(64) > B::ones(64));
+    assert!(B::one_at(130) > B::one_at(64) | B::one_at(0));
+    assert_eq!(B::ZERO.first_one(), None);
+    assert!(B::ones(192) == !B::ZERO);
+    let b = B::one_at(3) | B::one_at(70) | B::one_at(191);
+    assert_eq!(b.select_one(0), Some(3));
+    assert_eq!(b.select_one(1), Some(70));
+    assert_eq!(b.select_one(2), Some(191));
+    assert_eq!(b.select_one(3), None);
+}
+
+#[test]
+fn test_select_one() {
+    for word in [
+        0u64,
+        1,
+        0b1011_0000_0001,
+        u64::MAX,
+        0x8000_0000_0000_0001,
+        0x0123_4567_89ab_cdef,
+    ] {
+        let mut ones = (0..64).filter(|i| word & (1 << i) != 0);
+        for n in 0..=64 {
+            assert_eq!(select_u64(word, n), ones.next());
+        }
+    }
+    assert_eq!(0b1010u8.select_one(1), Some(3));
+    assert_eq!(0b1010u8.select_one(2), None);
+    assert_eq!((1u128 << 100 | 1 << 5).select_one(1), Some(100));
+    assert_eq!(u0.select_one(0), None);
+}
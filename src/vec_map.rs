@@ -0,0 +1,199 @@
+use crate::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+/// A complete mapping from keys of type `K` to values of type `V`, implemented using a [`Vec`]
+/// indexed by [`Finite::index_of`] of the key. Requires the `alloc` feature.
+///
+/// This has the same interface as [`ArrayMap`], but since it doesn't require [`ArrayFinite`], it
+/// can be used with any [`Finite`] key type, including generic ones.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// fn histogram<K: Finite>(items: impl IntoIterator<Item = K>) -> VecMap<K, usize> {
+///     let mut counts = VecMap::default();
+///     for item in items {
+///         counts[item] += 1;
+///     }
+///     counts
+/// }
+///
+/// let counts = histogram([true, false, true]);
+/// assert_eq!(counts[false], 1);
+/// assert_eq!(counts[true], 2);
+/// ```
+pub struct VecMap<K: Finite, V>(Vec<V>, PhantomData<K>);
+
+impl<K: Finite, V> VecMap<K, V> {
+    /// Constructs a new [`VecMap`] with initial values populated using the given function.
+    pub fn new(f: impl FnMut(K) -> V) -> Self {
+        VecMap(K::iter().map(f).collect(), PhantomData)
+    }
+
+    /// Constructs a new [`VecMap`] with initial values populated using the given fallible
+    /// function. If the function returns an error for any key, the error is returned and the
+    /// values created so far are dropped.
+    pub fn try_new<E>(f: impl FnMut(K) -> Result<V, E>) -> Result<Self, E> {
+        Ok(VecMap(
+            K::iter().map(f).collect::<Result<_, E>>()?,
+            PhantomData,
+        ))
+    }
+
+    /// Applies a mapping function the values of this map.
+    pub fn map_with_key<N>(&self, mut f: impl FnMut(K, &V) -> N) -> VecMap<K, N> {
+        VecMap(self.iter().map(|(k, v)| f(k, v)).collect(), PhantomData)
+    }
+
+    /// Applies a mapping function the values of this map.
+    pub fn map<N>(&self, f: impl FnMut(&V) -> N) -> VecMap<K, N> {
+        VecMap(self.0.iter().map(f).collect(), PhantomData)
+    }
+
+    /// Iterates over the keys and values of this map, in order of key index.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &V)> + ExactSizeIterator {
+        K::iter().zip(self.0.iter())
+    }
+
+    /// Iterates over the keys and mutable references to the values of this map, in order of key
+    /// index.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (K, &mut V)> + ExactSizeIterator {
+        K::iter().zip(self.0.iter_mut())
+    }
+
+    /// Iterates over the keys of this map, i.e. all values of `K`.
+    pub fn keys(&self) -> FiniteIter<K> {
+        K::iter()
+    }
+
+    /// Iterates over the values of this map, in order of key index.
+    pub fn values(&self) -> core::slice::Iter<'_, V> {
+        self.0.iter()
+    }
+
+    /// Iterates over mutable references to the values of this map, in order of key index.
+    pub fn values_mut(&mut self) -> core::slice::IterMut<'_, V> {
+        self.0.iter_mut()
+    }
+
+    /// Gets the values of this map as a slice, in order of key index.
+    pub fn as_slice(&self) -> &[V] {
+        &self.0
+    }
+
+    /// Gets the values of this map as a mutable slice, in order of key index.
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        &mut self.0
+    }
+}
+
+impl<K: Finite, V: Default> Default for VecMap<K, V> {
+    fn default() -> Self {
+        VecMap::new(|_| Default::default())
+    }
+}
+
+/// Constructs a map from key-value pairs. Keys which aren't given a value are assigned the default
+/// value, and for keys which are given multiple values, the last one is used.
+impl<K: Finite, V: Default> FromIterator<(K, V)> for VecMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map[key] = value;
+        }
+        map
+    }
+}
+
+impl<K: Finite, V> Index<K> for VecMap<K, V> {
+    type Output = V;
+    fn index(&self, index: K) -> &Self::Output {
+        let index = K::index_of(index);
+        unsafe { self.0.get_unchecked(index) }
+    }
+}
+
+impl<K: Finite, V> IndexMut<K> for VecMap<K, V> {
+    fn index_mut(&mut self, index: K) -> &mut Self::Output {
+        let index = K::index_of(index);
+        unsafe { self.0.get_unchecked_mut(index) }
+    }
+}
+
+impl<K: CompressFinite, V> Index<Compress<K>> for VecMap<K, V> {
+    type Output = V;
+    fn index(&self, index: Compress<K>) -> &Self::Output {
+        let index = Compress::index_of(index);
+        unsafe { self.0.get_unchecked(index) }
+    }
+}
+
+impl<K: CompressFinite, V> IndexMut<Compress<K>> for VecMap<K, V> {
+    fn index_mut(&mut self, index: Compress<K>) -> &mut Self::Output {
+        let index = Compress::index_of(index);
+        unsafe { self.0.get_unchecked_mut(index) }
+    }
+}
+
+impl<K: Finite, V: Clone> Clone for VecMap<K, V> {
+    fn clone(&self) -> Self {
+        VecMap(self.0.clone(), PhantomData)
+    }
+}
+
+impl<K: Finite, V: PartialEq> PartialEq for VecMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Finite, V: Eq> Eq for VecMap<K, V> {}
+
+impl<K: Finite, V: core::hash::Hash> core::hash::Hash for VecMap<K, V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<K: Finite, V: PartialOrd> PartialOrd for VecMap<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<K: Finite, V: Ord> Ord for VecMap<K, V> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<K: core::fmt::Debug + Finite, V: core::fmt::Debug> core::fmt::Debug for VecMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[test]
+fn test_vec_map() {
+    fn transpose<K: Finite, V: Finite>(map: &VecMap<K, V>) -> VecMap<V, Vec<K>> {
+        let mut res = VecMap::new(|_| Vec::new());
+        for (k, v) in map.iter() {
+            res[v.clone()].push(k);
+        }
+        res
+    }
+    let map = VecMap::new(|x: u8| x % 3 == 0);
+    let inv = transpose(&map);
+    assert_eq!(inv[true].len(), 86);
+    assert_eq!(inv[false].len(), 170);
+    let mut map = map.map_with_key(|k, v| if *v { k as u16 } else { 0 });
+    map[compress(3u8)] += 1;
+    assert_eq!(map[3], 4);
+    assert_eq!(map.values().copied().max(), Some(255));
+    let map: VecMap<bool, i32> = [(true, 5)].into_iter().collect();
+    assert_eq!(map.as_slice(), [0, 5]);
+    assert!(VecMap::try_new(|x: bool| if x { Err(()) } else { Ok(1) }).is_err());
+}
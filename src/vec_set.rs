@@ -0,0 +1,301 @@
+use crate::*;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+/// A set of values of type `T`, implemented using a sorted [`Vec`] of [`Compress`]ed values.
+/// Requires the `alloc` feature.
+///
+/// Unlike [`BitmapSet`] and [`ArraySet`], the memory used by this set is proportional to the
+/// number of values in it rather than the number of values of `T`, so this is preferable for
+/// sparse sets over types with many values.
+///
+/// # Example
+/// ```
+/// use cantor::*;
+///
+/// #[derive(Finite, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// struct Point(u16, u16);
+///
+/// let mut set = VecSet::none();
+/// set.include(Point(1000, 3));
+/// set.include(Point(5, 60000));
+/// assert_eq!(set.size(), 2);
+/// assert!(set.contains(Point(5, 60000)));
+/// assert!(set.iter().eq([Point(5, 60000), Point(1000, 3)]));
+/// ```
+pub struct VecSet<T: CompressFinite>(Vec<Compress<T>>);
+
+impl<T: CompressFinite> VecSet<T> {
+    /// The empty set.
+    pub const fn none() -> Self {
+        VecSet(Vec::new())
+    }
+
+    /// The set consisting of only the given value.
+    pub fn only(value: T) -> Self {
+        VecSet(alloc::vec![compress(value)])
+    }
+
+    /// The number of values in this set.
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Determines whether this is the empty set.
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Determines whether this is the empty set. This is equivalent to [`VecSet::is_none`].
+    pub fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    /// Determines whether this is the set of all possible values of `T`.
+    pub fn is_all(&self) -> bool {
+        self.0.len() == T::COUNT
+    }
+
+    /// Determines whether every value in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let mut other = other.0.iter().peekable();
+        self.0.iter().all(|a| {
+            while other.next_if(|b| *b < a).is_some() {}
+            other.next_if_eq(&a).is_some()
+        })
+    }
+
+    /// Determines whether every value in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Determines whether this set has no values in common with `other`.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let mut res = true;
+        merge(&self.0, &other.0, |_, in_a, in_b| res &= !(in_a && in_b));
+        res
+    }
+
+    /// Iterates over the values in this set, in increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator + '_ {
+        self.0.iter().map(|value| value.expand())
+    }
+
+    /// Constructs the set of values which are in `self` or `other`, depending on the given
+    /// predicate.
+    fn combine(&self, other: &Self, mut f: impl FnMut(bool, bool) -> bool) -> Self {
+        let mut res = Vec::new();
+        merge(&self.0, &other.0, |value, in_a, in_b| {
+            if f(in_a, in_b) {
+                res.push(*value);
+            }
+        });
+        VecSet(res)
+    }
+}
+
+/// Walks through the union of two sorted slices in order, calling `f` on each value along with
+/// whether it appears in each slice.
+fn merge<T: Ord>(a: &[T], b: &[T], mut f: impl FnMut(&T, bool, bool)) {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                f(&a[i], true, false);
+                i += 1;
+            }
+            Ordering::Greater => {
+                f(&b[j], false, true);
+                j += 1;
+            }
+            Ordering::Equal => {
+                f(&a[i], true, true);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    a[i..].iter().for_each(|value| f(value, true, false));
+    b[j..].iter().for_each(|value| f(value, false, true));
+}
+
+impl<T: CompressFinite> Default for VecSet<T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: CompressFinite> Set<T> for VecSet<T> {
+    fn contains(&self, value: T) -> bool {
+        self.0.binary_search(&compress(value)).is_ok()
+    }
+
+    fn include(&mut self, value: T) {
+        self.insert(value);
+    }
+
+    fn exclude(&mut self, value: T) {
+        self.remove(value);
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        let value = compress(value);
+        match self.0.binary_search(&value) {
+            Ok(_) => false,
+            Err(index) => {
+                self.0.insert(index, value);
+                true
+            }
+        }
+    }
+
+    fn remove(&mut self, value: T) -> bool {
+        match self.0.binary_search(&compress(value)) {
+            Ok(index) => {
+                self.0.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.size()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        *self = self.combine(other, |a, b| a || b);
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        self.0.retain(|value| other.0.binary_search(value).is_ok());
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        self.0.retain(|value| other.0.binary_search(value).is_err());
+    }
+}
+
+impl<T: CompressFinite> FromIterator<T> for VecSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values: Vec<_> = iter.into_iter().map(compress).collect();
+        values.sort_unstable();
+        values.dedup();
+        VecSet(values)
+    }
+}
+
+impl<T: CompressFinite> BitAnd<VecSet<T>> for VecSet<T> {
+    type Output = VecSet<T>;
+    fn bitand(self, rhs: VecSet<T>) -> Self::Output {
+        self.combine(&rhs, |a, b| a && b)
+    }
+}
+
+impl<T: CompressFinite> BitOr<VecSet<T>> for VecSet<T> {
+    type Output = VecSet<T>;
+    fn bitor(self, rhs: VecSet<T>) -> Self::Output {
+        self.combine(&rhs, |a, b| a || b)
+    }
+}
+
+impl<T: CompressFinite> BitXor<VecSet<T>> for VecSet<T> {
+    type Output = VecSet<T>;
+    fn bitxor(self, rhs: VecSet<T>) -> Self::Output {
+        self.combine(&rhs, |a, b| a != b)
+    }
+}
+
+impl<T: CompressFinite> Sub<VecSet<T>> for VecSet<T> {
+    type Output = VecSet<T>;
+    fn sub(self, rhs: VecSet<T>) -> Self::Output {
+        self.combine(&rhs, |a, b| a && !b)
+    }
+}
+
+impl<T: CompressFinite> BitOrAssign<VecSet<T>> for VecSet<T> {
+    fn bitor_assign(&mut self, rhs: VecSet<T>) {
+        self.union_with(&rhs);
+    }
+}
+
+impl<T: CompressFinite> BitAndAssign<VecSet<T>> for VecSet<T> {
+    fn bitand_assign(&mut self, rhs: VecSet<T>) {
+        self.intersect_with(&rhs);
+    }
+}
+
+impl<T: CompressFinite> BitXorAssign<VecSet<T>> for VecSet<T> {
+    fn bitxor_assign(&mut self, rhs: VecSet<T>) {
+        *self = self.combine(&rhs, |a, b| a != b);
+    }
+}
+
+impl<T: CompressFinite> SubAssign<VecSet<T>> for VecSet<T> {
+    fn sub_assign(&mut self, rhs: VecSet<T>) {
+        self.difference_with(&rhs);
+    }
+}
+
+impl<T: CompressFinite> Clone for VecSet<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: CompressFinite> PartialEq for VecSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: CompressFinite> Eq for VecSet<T> {}
+
+impl<T: CompressFinite> core::hash::Hash for VecSet<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: core::fmt::Debug + CompressFinite> core::fmt::Debug for VecSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[test]
+fn test_vec_set() {
+    let a: VecSet<u16> = (0..1000).filter(|x| x % 2 == 0).collect();
+    let b: VecSet<u16> = (0..1000).filter(|x| x % 3 == 0).rev().collect();
+    assert_eq!(a.size(), 500);
+    assert!(b.iter().eq((0..1000).step_by(3)));
+    assert!((a.clone() & b.clone()).iter().eq((0..1000).step_by(6)));
+    assert_eq!((a.clone() | b.clone()).size(), 500 + 334 - 167);
+    assert_eq!((a.clone() ^ b.clone()).size(), 500 + 334 - 2 * 167);
+    assert_eq!((a.clone() - b.clone()).size(), 500 - 167);
+    assert!((a.clone() - b.clone()).is_disjoint(&b));
+    assert!(!a.is_disjoint(&b));
+    assert!((a.clone() & b.clone()).is_subset(&a));
+    assert!(!a.is_subset(&b));
+    assert!(a.is_superset(&VecSet::only(998)));
+    let mut c = VecSet::none();
+    assert!(c.insert(5u16));
+    assert!(c.insert(2));
+    assert!(!c.insert(5));
+    assert!(c.iter().eq([2, 5]));
+    assert!(c.remove(2));
+    assert!(!c.remove(2));
+    assert_eq!(c, VecSet::only(5));
+    assert!((c - VecSet::only(5)).is_empty());
+    assert!(bool::iter().collect::<VecSet<_>>().is_all());
+}